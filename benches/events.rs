@@ -0,0 +1,76 @@
+//! Feeds synthetic baseview events through a `HeadlessWindow` to measure per-event overhead.
+
+use bevy::input::InputPlugin;
+use bevy::window::WindowPlugin;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use keyboard_types::Modifiers;
+
+use bevy_baseview::{BaseviewAppBuilder, EventBatching, HeadlessWindow};
+
+const EVENTS: usize = 10_000;
+
+fn window(batching: EventBatching) -> HeadlessWindow {
+    let window_open_options = baseview::WindowOpenOptions {
+        title: "events".to_string(),
+        size: baseview::Size::new(500.0, 400.0),
+        scale: baseview::WindowScalePolicy::ScaleFactor(1.0),
+        #[cfg(feature = "opengl")]
+        gl_config: None,
+    };
+
+    HeadlessWindow::new(
+        window_open_options,
+        BaseviewAppBuilder::new(move |app| {
+            app.add_plugins((InputPlugin, WindowPlugin::default()))
+                .insert_resource(batching)
+        }),
+    )
+    .unwrap()
+}
+
+/// Mostly cursor motion, with a click every ten events.
+fn events() -> Vec<baseview::Event> {
+    (0..EVENTS)
+        .map(|i| {
+            let event = match i % 10 {
+                0 => baseview::MouseEvent::ButtonPressed {
+                    button: baseview::MouseButton::Left,
+                    modifiers: Modifiers::empty(),
+                },
+                5 => baseview::MouseEvent::ButtonReleased {
+                    button: baseview::MouseButton::Left,
+                    modifiers: Modifiers::empty(),
+                },
+                _ => baseview::MouseEvent::CursorMoved {
+                    position: baseview::Point::new((i % 500) as f64, (i % 400) as f64),
+                    modifiers: Modifiers::empty(),
+                },
+            };
+            baseview::Event::Mouse(event)
+        })
+        .collect()
+}
+
+fn process_events(c: &mut Criterion) {
+    let events = events();
+
+    for (name, batching) in [
+        ("process 10k mouse events", EventBatching::Immediate),
+        ("process 10k coalesced mouse events", EventBatching::Coalesced),
+    ] {
+        let mut window = window(batching);
+
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                for event in &events {
+                    black_box(window.send_event(event.clone()));
+                }
+                // The frame that converts queued events and clears the event queues.
+                window.update();
+            })
+        });
+    }
+}
+
+criterion_group!(benches, process_events);
+criterion_main!(benches);
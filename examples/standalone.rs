@@ -0,0 +1,57 @@
+//! Opens a Bevy app in its own baseview window, without a host or another windowing crate.
+
+use baseview::gl::GlConfig;
+use bevy::app::App;
+use bevy::prelude::*;
+
+use bevy_baseview::DefaultBaseviewPlugins;
+
+// Window size (logical).
+const WINDOW_WIDTH: f64 = 500.0;
+const WINDOW_HEIGHT: f64 = 400.0;
+
+fn main() {
+    let window_open_options = baseview::WindowOpenOptions {
+        title: "Standalone example".to_string(),
+        size: baseview::Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        scale: baseview::WindowScalePolicy::SystemScaleFactor,
+        #[cfg(feature = "opengl")]
+        gl_config: Some(GlConfig {
+            version: (3, 2),
+            red_bits: 8,
+            blue_bits: 8,
+            green_bits: 8,
+            alpha_bits: 8,
+            depth_bits: 24,
+            stencil_bits: 8,
+            samples: None,
+            srgb: true,
+            double_buffer: true,
+            vsync: true,
+            ..Default::default()
+        }),
+    };
+
+    if let Err(err) = bevy_baseview::open_blocking(window_open_options, build) {
+        eprintln!("{}", err);
+    }
+}
+
+fn build(app: &mut App) -> &mut App {
+    app.add_plugins(DefaultBaseviewPlugins)
+        .add_plugins(bevy::log::LogPlugin::default())
+        .add_systems(Startup, setup)
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(Camera2d);
+
+    commands.spawn((
+        Mesh2d(meshes.add(Circle::new(100.0))),
+        MeshMaterial2d(materials.add(Color::hsl(200.0, 0.95, 0.7))),
+    ));
+}
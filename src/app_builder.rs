@@ -0,0 +1,258 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use bevy::app::{App, PluginsState};
+use bevy::ecs::system::SystemState;
+use bevy::log::info;
+use bevy::prelude::{Commands, Entity, EventWriter, FromWorld, Query, With};
+use bevy::window::{
+    PrimaryWindow, RawHandleWrapper, RawHandleWrapperHolder, Window, WindowCreated, WindowWrapper,
+};
+
+use crate::error::{self, BaseviewError};
+use crate::parent_window::RawWindow;
+
+type AppHook = Box<dyn FnOnce(&mut App) + Send>;
+type WindowHook = Box<dyn FnOnce(&mut App, &mut baseview::Window) + Send>;
+
+type AttachWindowParams = (
+    Commands<'static, 'static>,
+    Query<'static, 'static, (Entity, &'static mut Window), With<PrimaryWindow>>,
+    EventWriter<'static, WindowCreated>,
+);
+
+/// How the Bevy app is set up once baseview has opened its window.
+///
+/// The steps, in order:
+/// 1. run the app builder and every [`pre_init`](Self::pre_init) hook,
+/// 2. spawn the [`primary_window`](Self::primary_window) if the app has none, so `Startup`
+///    systems see it,
+/// 3. wait for plugins to be ready, then `finish` and `cleanup`,
+/// 4. run [`initial_updates`](Self::initial_updates) updates,
+/// 5. give the primary window baseview's handles and send `WindowCreated`, unless
+///    [`insert_handles`](Self::insert_handles) is off,
+/// 6. run every [`post_init`](Self::post_init) hook.
+///
+/// Every entry point also accepts a plain `FnOnce(&mut App) -> &mut App`, which is turned
+/// into a builder with the defaults.
+pub struct BaseviewAppBuilder {
+    app_builder: AppHook,
+    pre_init: Vec<AppHook>,
+    post_init: Vec<WindowHook>,
+    initial_updates: usize,
+    primary_window: Option<Window>,
+    insert_handles: bool,
+}
+
+impl BaseviewAppBuilder {
+    pub fn new(app_builder: impl FnOnce(&mut App) -> &mut App + Send + 'static) -> Self {
+        Self {
+            app_builder: Box::new(move |app| {
+                app_builder(app);
+            }),
+            pre_init: Vec::new(),
+            post_init: Vec::new(),
+            initial_updates: 1,
+            primary_window: None,
+            insert_handles: true,
+        }
+    }
+
+    /// Runs `hook` after the app builder, before plugins are finished.
+    pub fn pre_init(mut self, hook: impl FnOnce(&mut App) + Send + 'static) -> Self {
+        self.pre_init.push(Box::new(hook));
+        self
+    }
+
+    /// Runs `hook` once the primary window is attached to the baseview window.
+    pub fn post_init(
+        mut self,
+        hook: impl FnOnce(&mut App, &mut baseview::Window) + Send + 'static,
+    ) -> Self {
+        self.post_init.push(Box::new(hook));
+        self
+    }
+
+    /// How many times to update the app before attaching the window. Defaults to 1.
+    pub fn initial_updates(mut self, initial_updates: usize) -> Self {
+        self.initial_updates = initial_updates;
+        self
+    }
+
+    /// Spawns `window` as the primary window if the app did not create one, e.g. because
+    /// `WindowPlugin::primary_window` is `None`.
+    pub fn primary_window(mut self, window: Window) -> Self {
+        self.primary_window = Some(window);
+        self
+    }
+
+    /// Whether to insert `RawHandleWrapper` on the primary window and send `WindowCreated`.
+    ///
+    /// Turn it off to attach the window yourself, e.g. from a `post_init` hook with
+    /// `baseview::Window`'s handles. Defaults to `true`.
+    pub fn insert_handles(mut self, insert_handles: bool) -> Self {
+        self.insert_handles = insert_handles;
+        self
+    }
+
+    /// Builds the app for the freshly opened baseview `window`.
+    pub(crate) fn build(self, window: &mut baseview::Window) -> Result<App, BaseviewError> {
+        let insert_handles = self.insert_handles;
+        let (mut app, post_init) = self.init(|_| {})?;
+
+        if insert_handles {
+            attach_window(&mut app, window)?;
+        }
+
+        catch_build_panic(move || {
+            for hook in post_init {
+                hook(&mut app, window);
+            }
+            app
+        })
+    }
+
+    /// Builds the app without a baseview window, for [`HeadlessWindow`](crate::HeadlessWindow).
+    ///
+    /// `configure` runs once the primary window is spawned, before plugins are finished and
+    /// before the first update. Handles are never inserted and `post_init` hooks do not run,
+    /// as both need a window.
+    pub(crate) fn build_headless(
+        self,
+        configure: impl FnOnce(&mut App),
+    ) -> Result<App, BaseviewError> {
+        let (app, _post_init) = self.init(configure)?;
+        Ok(app)
+    }
+
+    /// Steps 1 to 4, which do not need the baseview window, with `configure` run after
+    /// step 2.
+    fn init(
+        self,
+        configure: impl FnOnce(&mut App),
+    ) -> Result<(App, Vec<WindowHook>), BaseviewError> {
+        let Self {
+            app_builder,
+            pre_init,
+            post_init,
+            initial_updates,
+            primary_window,
+            insert_handles: _,
+        } = self;
+
+        let app = catch_build_panic(move || {
+            let mut app = App::new();
+            app_builder(&mut app);
+            for hook in pre_init {
+                hook(&mut app);
+            }
+
+            if let Some(primary_window) = primary_window {
+                let world = app.world_mut();
+                let has_primary_window = world
+                    .query_filtered::<(), With<PrimaryWindow>>()
+                    .iter(world)
+                    .next()
+                    .is_some();
+                if !has_primary_window {
+                    world.spawn((primary_window, PrimaryWindow));
+                }
+            }
+            configure(&mut app);
+
+            while app.plugins_state() == PluginsState::Adding {
+                bevy::tasks::tick_global_task_pools_on_main_thread();
+            }
+            app.finish();
+            app.cleanup();
+
+            for _ in 0..initial_updates {
+                app.update();
+            }
+            app
+        })?;
+
+        Ok((app, post_init))
+    }
+}
+
+impl<F> From<F> for BaseviewAppBuilder
+where
+    F: FnOnce(&mut App) -> &mut App + Send + 'static,
+{
+    fn from(app_builder: F) -> Self {
+        BaseviewAppBuilder::new(app_builder)
+    }
+}
+
+fn catch_build_panic(build: impl FnOnce() -> App) -> Result<App, BaseviewError> {
+    panic::catch_unwind(AssertUnwindSafe(build))
+        .map_err(|payload| BaseviewError::AppBuild(error::panic_message(&*payload)))
+}
+
+/// Gives the primary window baseview's handles and announces it with `WindowCreated`.
+fn attach_window(app: &mut App, window: &mut baseview::Window) -> Result<(), BaseviewError> {
+    let mut create_window_system_state: SystemState<AttachWindowParams> =
+        SystemState::from_world(app.world_mut());
+
+    let (mut commands, mut windows, mut event_writer) =
+        create_window_system_state.get_mut(app.world_mut());
+
+    let (entity, window_comp) = windows.get_single_mut()?;
+
+    info!(
+        "Creating new window {:?} ({:?})",
+        window_comp.title.as_str(),
+        entity
+    );
+
+    let window_wrapper = WindowWrapper::new(RawWindow::new(window)?);
+    let handle_wrapper = RawHandleWrapper::new(&window_wrapper)?;
+
+    commands
+        .entity(entity)
+        .insert(handle_wrapper.clone())
+        .insert(RawHandleWrapperHolder(Arc::new(Mutex::new(Some(
+            handle_wrapper.clone(),
+        )))));
+
+    event_writer.send(WindowCreated { window: entity });
+
+    create_window_system_state.apply(app.world_mut());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use bevy::prelude::Startup;
+    use bevy::window::WindowPlugin;
+
+    use super::*;
+
+    #[test]
+    fn startup_systems_see_the_primary_window() {
+        let saw_window = Arc::new(AtomicBool::new(false));
+        let flag = saw_window.clone();
+
+        BaseviewAppBuilder::new(move |app| {
+            app.add_plugins(WindowPlugin {
+                primary_window: None,
+                ..Default::default()
+            })
+            .add_systems(
+                Startup,
+                move |windows: Query<&Window, With<PrimaryWindow>>| {
+                    flag.store(windows.get_single().is_ok(), Ordering::SeqCst);
+                },
+            )
+        })
+        .primary_window(Window::default())
+        .build_headless(|_| {})
+        .unwrap();
+
+        assert!(saw_window.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::ecs::system::Resource;
+
+/// When baseview events are turned into Bevy events.
+///
+/// The status baseview expects back from `on_event` (see
+/// [`KeyboardCapturePolicy`](crate::KeyboardCapturePolicy)) is always decided as the event
+/// arrives; only the conversion is deferred. `WindowEvent::WillClose` is never deferred.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EventBatching {
+    /// Convert every event as soon as baseview delivers it.
+    Immediate,
+    /// Queue events until the next frame, then convert them in order. Keeps every cursor
+    /// sample, e.g. for drawing envelopes.
+    Queued,
+    /// Like [`Queued`](Self::Queued), but consecutive cursor moves collapse into the last
+    /// one. `MouseMotion` still reports the whole distance, and button and key events keep
+    /// their place between moves.
+    #[default]
+    Coalesced,
+}
+
+pub fn is_cursor_moved(event: &baseview::Event) -> bool {
+    matches!(
+        event,
+        baseview::Event::Mouse(baseview::MouseEvent::CursorMoved { .. })
+    )
+}
+
+pub fn is_will_close(event: &baseview::Event) -> bool {
+    matches!(
+        event,
+        baseview::Event::Window(baseview::WindowEvent::WillClose)
+    )
+}
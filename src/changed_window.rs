@@ -0,0 +1,149 @@
+use bevy::window::Window;
+
+use crate::native_window::NativeWindow;
+use crate::resize::{self, ResizeNegotiation};
+
+/// Propagates changes Bevy systems made to the primary [`Window`] to the baseview window.
+///
+/// Mirrors bevy_winit's `changed_windows`: `cache` is the window as last applied and only
+/// fields that differ from it are touched. Returns whether anything differed, in which
+/// case the caller should refresh its cache.
+///
+/// Size changes are fitted to the resize constraints and must be accepted by the host
+/// through [`ResizeNegotiation`]; a refused size is reverted in `window`.
+///
+/// baseview offers no way to change anything else. A plugin editor's title in particular
+/// is owned by the host, which names the window after the plugin, so title changes are
+/// not applied; like the other fields they are reported once with a warning and left as
+/// set in `window`.
+pub fn apply(
+    window: &mut Window,
+    cache: &Window,
+    negotiation: Option<&ResizeNegotiation>,
+    baseview_window: &mut impl NativeWindow,
+) -> bool {
+    let mut changed = false;
+
+    if window.resolution.size() != cache.resolution.size()
+        || window.resize_constraints != cache.resize_constraints
+    {
+        let size = resize::constrain(
+            window.resolution.size(),
+            &window.resize_constraints,
+            negotiation.and_then(|negotiation| negotiation.aspect_ratio),
+        );
+
+        if size == cache.resolution.size() {
+            window.resolution.set(size.x, size.y);
+        } else if negotiation.is_none_or(|negotiation| negotiation.request(size)) {
+            window.resolution.set(size.x, size.y);
+            baseview_window.resize(baseview::Size::new(size.x as f64, size.y as f64));
+        } else {
+            log::info!("Host refused resizing the editor to {:?}", size);
+            window
+                .resolution
+                .set(cache.resolution.width(), cache.resolution.height());
+        }
+        changed = true;
+    }
+
+    changed |= unsupported("title", &window.title, &cache.title);
+    changed |= unsupported("mode", &window.mode, &cache.mode);
+    changed |= unsupported("position", &window.position, &cache.position);
+    changed |= unsupported("resizable", &window.resizable, &cache.resizable);
+    changed |= unsupported("decorations", &window.decorations, &cache.decorations);
+    changed |= unsupported("visible", &window.visible, &cache.visible);
+    changed |= unsupported("window_level", &window.window_level, &cache.window_level);
+
+    changed
+}
+
+fn unsupported<T: PartialEq + std::fmt::Debug>(field: &str, value: &T, cached: &T) -> bool {
+    if value == cached {
+        return false;
+    }
+
+    log::warn!(
+        "Window::{} changed to {:?}, which baseview does not support",
+        field,
+        value
+    );
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Vec2;
+    use bevy::window::WindowResizeConstraints;
+
+    use super::*;
+    use crate::native_window::RecordingWindow;
+
+    #[test]
+    fn unchanged_windows_touch_nothing() {
+        let mut window = Window::default();
+        let cache = window.clone();
+        let mut native = RecordingWindow::default();
+
+        assert!(!apply(&mut window, &cache, None, &mut native));
+        assert!(native.sizes.is_empty());
+    }
+
+    #[test]
+    fn size_changes_resize_the_native_window() {
+        let cache = Window::default();
+        let mut window = cache.clone();
+        window.resolution.set(400.0, 300.0);
+        let mut native = RecordingWindow::default();
+
+        assert!(apply(&mut window, &cache, None, &mut native));
+        assert_eq!(native.sizes, vec![baseview::Size::new(400.0, 300.0)]);
+    }
+
+    #[test]
+    fn size_changes_are_constrained() {
+        let cache = Window::default();
+        let mut window = cache.clone();
+        window.resize_constraints = WindowResizeConstraints {
+            max_width: 500.0,
+            ..Default::default()
+        };
+        window.resolution.set(800.0, 300.0);
+        let mut native = RecordingWindow::default();
+
+        assert!(apply(&mut window, &cache, None, &mut native));
+        assert_eq!(window.resolution.size(), Vec2::new(500.0, 300.0));
+        assert_eq!(native.sizes, vec![baseview::Size::new(500.0, 300.0)]);
+    }
+
+    #[test]
+    fn refused_sizes_are_reverted() {
+        let cache = Window::default();
+        let mut window = cache.clone();
+        window.resolution.set(400.0, 300.0);
+        let negotiation = ResizeNegotiation::new(|_| false);
+        let mut native = RecordingWindow::default();
+
+        assert!(apply(&mut window, &cache, Some(&negotiation), &mut native));
+        assert_eq!(window.resolution.size(), cache.resolution.size());
+        assert!(native.sizes.is_empty());
+    }
+
+    #[test]
+    fn unsupported_changes_are_reported_but_not_applied() {
+        let cache = Window::default();
+        let mut native = RecordingWindow::default();
+
+        let mut window = cache.clone();
+        window.resizable = !cache.resizable;
+        assert!(apply(&mut window, &cache, None, &mut native));
+        assert_eq!(window.resizable, !cache.resizable);
+
+        let mut window = cache.clone();
+        window.title = "Renamed".to_string();
+        assert!(apply(&mut window, &cache, None, &mut native));
+        assert_eq!(window.title, "Renamed");
+
+        assert!(native.sizes.is_empty());
+    }
+}
@@ -1,13 +1,118 @@
 use bevy::input::mouse::MouseButton;
+use bevy::window::SystemCursorIcon;
+
+use crate::cursor_icon::BaseviewCursorIcon;
 
 pub fn baseview_mousebutton_to_bevy(button: baseview::MouseButton) -> MouseButton {
     match button {
         baseview::MouseButton::Left => MouseButton::Left,
         baseview::MouseButton::Middle => MouseButton::Middle,
         baseview::MouseButton::Right => MouseButton::Right,
+        baseview::MouseButton::Back => MouseButton::Back,
+        baseview::MouseButton::Forward => MouseButton::Forward,
         baseview::MouseButton::Other(val) => MouseButton::Other(val as u16),
-        _ => {
-            MouseButton::Other(0) // TODO: Figure out back/forward mapping
+    }
+}
+
+pub fn bevy_cursor_icon_to_baseview(cursor: &BaseviewCursorIcon) -> baseview::MouseCursor {
+    bevy_system_cursor_to_baseview(cursor.0)
+}
+
+pub fn bevy_system_cursor_to_baseview(icon: SystemCursorIcon) -> baseview::MouseCursor {
+    match icon {
+        SystemCursorIcon::Default => baseview::MouseCursor::Default,
+        SystemCursorIcon::ContextMenu => baseview::MouseCursor::Default,
+        SystemCursorIcon::Help => baseview::MouseCursor::Help,
+        SystemCursorIcon::Pointer => baseview::MouseCursor::Hand,
+        SystemCursorIcon::Progress => baseview::MouseCursor::PtrWorking,
+        SystemCursorIcon::Wait => baseview::MouseCursor::Working,
+        SystemCursorIcon::Cell => baseview::MouseCursor::Cell,
+        SystemCursorIcon::Crosshair => baseview::MouseCursor::Crosshair,
+        SystemCursorIcon::Text => baseview::MouseCursor::Text,
+        SystemCursorIcon::VerticalText => baseview::MouseCursor::VerticalText,
+        SystemCursorIcon::Alias => baseview::MouseCursor::Alias,
+        SystemCursorIcon::Copy => baseview::MouseCursor::Copy,
+        SystemCursorIcon::Move => baseview::MouseCursor::Move,
+        SystemCursorIcon::NoDrop => baseview::MouseCursor::PtrNotAllowed,
+        SystemCursorIcon::NotAllowed => baseview::MouseCursor::NotAllowed,
+        SystemCursorIcon::Grab => baseview::MouseCursor::Hand,
+        SystemCursorIcon::Grabbing => baseview::MouseCursor::HandGrabbing,
+        SystemCursorIcon::EResize => baseview::MouseCursor::EResize,
+        SystemCursorIcon::NResize => baseview::MouseCursor::NResize,
+        SystemCursorIcon::NeResize => baseview::MouseCursor::NeResize,
+        SystemCursorIcon::NwResize => baseview::MouseCursor::NwResize,
+        SystemCursorIcon::SResize => baseview::MouseCursor::SResize,
+        SystemCursorIcon::SeResize => baseview::MouseCursor::SeResize,
+        SystemCursorIcon::SwResize => baseview::MouseCursor::SwResize,
+        SystemCursorIcon::WResize => baseview::MouseCursor::WResize,
+        SystemCursorIcon::EwResize => baseview::MouseCursor::EwResize,
+        SystemCursorIcon::NsResize => baseview::MouseCursor::NsResize,
+        SystemCursorIcon::NeswResize => baseview::MouseCursor::NeswResize,
+        SystemCursorIcon::NwseResize => baseview::MouseCursor::NwseResize,
+        SystemCursorIcon::ColResize => baseview::MouseCursor::ColResize,
+        SystemCursorIcon::RowResize => baseview::MouseCursor::RowResize,
+        SystemCursorIcon::AllScroll => baseview::MouseCursor::AllScroll,
+        SystemCursorIcon::ZoomIn => baseview::MouseCursor::ZoomIn,
+        SystemCursorIcon::ZoomOut => baseview::MouseCursor::ZoomOut,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mouse_buttons_map_to_bevy_buttons() {
+        let table = [
+            (baseview::MouseButton::Left, MouseButton::Left),
+            (baseview::MouseButton::Middle, MouseButton::Middle),
+            (baseview::MouseButton::Right, MouseButton::Right),
+            (baseview::MouseButton::Back, MouseButton::Back),
+            (baseview::MouseButton::Forward, MouseButton::Forward),
+            (baseview::MouseButton::Other(0), MouseButton::Other(0)),
+            (baseview::MouseButton::Other(6), MouseButton::Other(6)),
+            (baseview::MouseButton::Other(255), MouseButton::Other(255)),
+        ];
+
+        for (button, expected) in table {
+            assert_eq!(baseview_mousebutton_to_bevy(button), expected, "{button:?}");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn system_cursors_map_to_baseview_cursors() {
+        let table = [
+            (SystemCursorIcon::Default, baseview::MouseCursor::Default),
+            (SystemCursorIcon::Pointer, baseview::MouseCursor::Hand),
+            (SystemCursorIcon::Grab, baseview::MouseCursor::Hand),
+            (SystemCursorIcon::Grabbing, baseview::MouseCursor::HandGrabbing),
+            (SystemCursorIcon::Text, baseview::MouseCursor::Text),
+            (SystemCursorIcon::Wait, baseview::MouseCursor::Working),
+            (SystemCursorIcon::Progress, baseview::MouseCursor::PtrWorking),
+            (SystemCursorIcon::NotAllowed, baseview::MouseCursor::NotAllowed),
+            (SystemCursorIcon::NoDrop, baseview::MouseCursor::PtrNotAllowed),
+            (SystemCursorIcon::EwResize, baseview::MouseCursor::EwResize),
+            (SystemCursorIcon::NsResize, baseview::MouseCursor::NsResize),
+            (SystemCursorIcon::ColResize, baseview::MouseCursor::ColResize),
+            (SystemCursorIcon::RowResize, baseview::MouseCursor::RowResize),
+            (SystemCursorIcon::Crosshair, baseview::MouseCursor::Crosshair),
+            (SystemCursorIcon::ZoomIn, baseview::MouseCursor::ZoomIn),
+        ];
+
+        for (icon, expected) in table {
+            assert_eq!(bevy_system_cursor_to_baseview(icon), expected, "{icon:?}");
+        }
+    }
+
+    #[test]
+    fn cursor_icon_component_uses_system_icon() {
+        assert_eq!(
+            bevy_cursor_icon_to_baseview(&SystemCursorIcon::Move.into()),
+            baseview::MouseCursor::Move
+        );
+        assert_eq!(
+            bevy_cursor_icon_to_baseview(&BaseviewCursorIcon::default()),
+            baseview::MouseCursor::Default
+        );
+    }
+}
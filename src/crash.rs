@@ -0,0 +1,27 @@
+use bevy::ecs::system::Resource;
+
+/// Called with the panic message when a Bevy system panics inside the editor.
+///
+/// Panics are caught at the baseview callback boundary rather than unwinding into the host.
+/// The app is then dropped and the editor stops responding until the host closes it, so
+/// this is the place to report the failure, e.g. through the plugin's host logging.
+///
+/// Insert it while building the app; it is taken out of the world when the window opens.
+#[derive(Resource)]
+pub struct CrashHandler(Box<dyn Fn(&str) + Send + Sync>);
+
+impl CrashHandler {
+    pub fn new(on_crash: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        Self(Box::new(on_crash))
+    }
+
+    pub(crate) fn report(&self, message: &str) {
+        (self.0)(message)
+    }
+}
+
+impl std::fmt::Debug for CrashHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CrashHandler").finish_non_exhaustive()
+    }
+}
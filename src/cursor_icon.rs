@@ -0,0 +1,15 @@
+use bevy::ecs::component::Component;
+use bevy::window::SystemCursorIcon;
+
+/// The cursor shown over a window, the baseview counterpart of bevy_winit's `CursorIcon`.
+///
+/// Insert it on the primary window entity. baseview only offers the system cursors, so
+/// there is no custom image variant; windows without it show the default cursor.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BaseviewCursorIcon(pub SystemCursorIcon);
+
+impl From<SystemCursorIcon> for BaseviewCursorIcon {
+    fn from(icon: SystemCursorIcon) -> Self {
+        Self(icon)
+    }
+}
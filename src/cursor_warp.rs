@@ -0,0 +1,142 @@
+use std::time::{Duration, Instant};
+
+use bevy::math::DVec2;
+use rwh_05::{HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle, RawWindowHandle};
+
+/// How close a reported move must land to a warp target to be taken for the warp itself.
+const ECHO_DISTANCE: f64 = 1.0;
+
+/// How long to wait for the move the OS reports for a warp before assuming it was dropped.
+const ECHO_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Moves the pointer to `position`, in baseview's logical window coordinates.
+///
+/// baseview has no API for this, so it goes through the window's native handle. Only X11
+/// is supported, through the Xlib display baseview already opened; elsewhere this returns
+/// `false` and a locked cursor stays where it is.
+pub fn warp_cursor(window: &baseview::Window, position: DVec2, scale_factor: f64) -> bool {
+    warp(
+        window.raw_window_handle(),
+        window.raw_display_handle(),
+        position,
+        scale_factor,
+    )
+}
+
+#[cfg(target_os = "linux")]
+fn warp(
+    window: RawWindowHandle,
+    display: RawDisplayHandle,
+    position: DVec2,
+    scale_factor: f64,
+) -> bool {
+    let (RawWindowHandle::Xlib(window), RawDisplayHandle::Xlib(display)) = (window, display)
+    else {
+        return false;
+    };
+    if display.display.is_null() {
+        return false;
+    }
+
+    let position = (position * scale_factor).round();
+    let display = display.display as *mut x11::xlib::Display;
+    unsafe {
+        x11::xlib::XWarpPointer(
+            display,
+            0,
+            window.window,
+            0,
+            0,
+            0,
+            0,
+            position.x as i32,
+            position.y as i32,
+        );
+        x11::xlib::XFlush(display);
+    }
+    true
+}
+
+#[cfg(not(target_os = "linux"))]
+fn warp(
+    _window: RawWindowHandle,
+    _display: RawDisplayHandle,
+    _position: DVec2,
+    _scale_factor: f64,
+) -> bool {
+    false
+}
+
+/// The last warp, until the OS reports it back as a pointer move.
+///
+/// That move is not user input and must not become `MouseMotion`. The OS may also never
+/// report it, e.g. when the user moved first, so a warp is forgotten after a short while.
+#[derive(Debug, Default)]
+pub struct PendingWarp {
+    warp: Option<(DVec2, Instant)>,
+}
+
+impl PendingWarp {
+    pub fn start(&mut self, target: DVec2, now: Instant) {
+        self.warp = Some((target, now));
+    }
+
+    pub fn clear(&mut self) {
+        self.warp = None;
+    }
+
+    /// Whether a warp may still be reported at `now`, forgetting it if it timed out.
+    pub fn is_pending(&mut self, now: Instant) -> bool {
+        if self
+            .warp
+            .is_some_and(|(_, started)| now.saturating_duration_since(started) >= ECHO_TIMEOUT)
+        {
+            self.warp = None;
+        }
+        self.warp.is_some()
+    }
+
+    /// Whether `position` is the move reported for the pending warp, clearing it if so.
+    pub fn take_echo(&mut self, position: DVec2) -> bool {
+        let is_echo = self
+            .warp
+            .is_some_and(|(target, _)| target.distance(position) < ECHO_DISTANCE);
+        if is_echo {
+            self.warp = None;
+        }
+        is_echo
+    }
+
+    /// Whether a cursor at `position` is far enough from `target` to warp it back.
+    pub fn should_warp(position: DVec2, target: DVec2) -> bool {
+        position.distance(target) >= ECHO_DISTANCE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn echoes_clear_the_warp() {
+        let mut pending_warp = PendingWarp::default();
+        let start = Instant::now();
+        pending_warp.start(DVec2::new(50.0, 50.0), start);
+
+        assert!(!pending_warp.take_echo(DVec2::new(70.0, 50.0)));
+        assert!(pending_warp.is_pending(start));
+        assert!(pending_warp.take_echo(DVec2::new(50.4, 50.0)));
+        assert!(!pending_warp.is_pending(start));
+    }
+
+    #[test]
+    fn unreported_warps_time_out() {
+        let mut pending_warp = PendingWarp::default();
+        let start = Instant::now();
+        pending_warp.start(DVec2::new(50.0, 50.0), start);
+
+        assert!(pending_warp.is_pending(start + ECHO_TIMEOUT / 2));
+        assert!(!pending_warp.is_pending(start + ECHO_TIMEOUT));
+        assert!(!pending_warp.take_echo(DVec2::new(50.0, 50.0)));
+    }
+}
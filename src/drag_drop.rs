@@ -0,0 +1,94 @@
+use bevy::ecs::system::Resource;
+use bevy::prelude::Entity;
+use bevy::window::FileDragAndDrop;
+
+/// Whether the editor accepts what is currently being dragged over it.
+///
+/// Systems set this while handling `FileDragAndDrop::HoveredFile`, typically after checking
+/// what is under the cursor. The host is answered from `on_event`, before the app has seen
+/// the latest drag position, so the answer lags one frame behind. It is reset when the drag
+/// leaves or drops.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct DropTarget {
+    /// The effect to report to the host, or `None` to refuse the drop.
+    pub accept: Option<baseview::DropEffect>,
+}
+
+impl DropTarget {
+    pub fn status(&self) -> baseview::EventStatus {
+        match self.accept {
+            Some(effect) => baseview::EventStatus::AcceptDrop(effect),
+            None => baseview::EventStatus::Ignored,
+        }
+    }
+}
+
+/// One `HoveredFile` per dragged file.
+pub fn hovered(window: Entity, data: &baseview::DropData) -> Vec<FileDragAndDrop> {
+    files(data)
+        .map(|path_buf| FileDragAndDrop::HoveredFile {
+            window,
+            path_buf: path_buf.clone(),
+        })
+        .collect()
+}
+
+/// One `DroppedFile` per dropped file.
+pub fn dropped(window: Entity, data: &baseview::DropData) -> Vec<FileDragAndDrop> {
+    files(data)
+        .map(|path_buf| FileDragAndDrop::DroppedFile {
+            window,
+            path_buf: path_buf.clone(),
+        })
+        .collect()
+}
+
+fn files(data: &baseview::DropData) -> impl Iterator<Item = &std::path::PathBuf> {
+    match data {
+        baseview::DropData::Files(paths) => paths.iter(),
+        baseview::DropData::None => [].iter(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn every_file_is_reported() {
+        let window = Entity::from_raw(0);
+        let data = baseview::DropData::Files(vec![
+            PathBuf::from("kick.wav"),
+            PathBuf::from("snare.wav"),
+        ]);
+
+        assert_eq!(
+            dropped(window, &data),
+            vec![
+                FileDragAndDrop::DroppedFile {
+                    window,
+                    path_buf: PathBuf::from("kick.wav"),
+                },
+                FileDragAndDrop::DroppedFile {
+                    window,
+                    path_buf: PathBuf::from("snare.wav"),
+                },
+            ]
+        );
+        assert!(hovered(window, &baseview::DropData::None).is_empty());
+    }
+
+    #[test]
+    fn drops_are_refused_by_default() {
+        assert_eq!(DropTarget::default().status(), baseview::EventStatus::Ignored);
+        assert_eq!(
+            DropTarget {
+                accept: Some(baseview::DropEffect::Copy),
+            }
+            .status(),
+            baseview::EventStatus::AcceptDrop(baseview::DropEffect::Copy)
+        );
+    }
+}
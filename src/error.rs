@@ -0,0 +1,90 @@
+use std::any::Any;
+use std::fmt;
+
+use bevy::ecs::query::QuerySingleError;
+
+/// Why the editor window could not be opened.
+#[derive(Debug, Clone)]
+pub enum BaseviewError {
+    /// The built app has no entity with `PrimaryWindow`, e.g. because `WindowPlugin`
+    /// was added with `primary_window: None`.
+    NoPrimaryWindow,
+    /// The built app has more than one entity with `PrimaryWindow`.
+    MultiplePrimaryWindows,
+    /// baseview's window or display handle has no raw-window-handle 0.6 equivalent.
+    UnsupportedHandle(String),
+    /// A raw handle had a null pointer or zero id in the named field.
+    NullHandle(&'static str),
+    /// The converted handles were rejected when handing them to Bevy.
+    Handle(rwh_06::HandleError),
+    /// The app builder or a plugin panicked while the app was being built.
+    AppBuild(String),
+}
+
+impl fmt::Display for BaseviewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaseviewError::NoPrimaryWindow => write!(f, "the app has no primary window"),
+            BaseviewError::MultiplePrimaryWindows => {
+                write!(f, "the app has more than one primary window")
+            }
+            BaseviewError::UnsupportedHandle(handle) => {
+                write!(f, "unsupported raw window handle: {}", handle)
+            }
+            BaseviewError::NullHandle(field) => write!(f, "raw window handle has a null {}", field),
+            BaseviewError::Handle(err) => write!(f, "invalid window handle: {}", err),
+            BaseviewError::AppBuild(message) => write!(f, "building the app panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for BaseviewError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BaseviewError::Handle(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<QuerySingleError> for BaseviewError {
+    fn from(err: QuerySingleError) -> Self {
+        match err {
+            QuerySingleError::NoEntities(_) => BaseviewError::NoPrimaryWindow,
+            QuerySingleError::MultipleEntities(_) => BaseviewError::MultiplePrimaryWindows,
+        }
+    }
+}
+
+impl From<rwh_06::HandleError> for BaseviewError {
+    fn from(err: rwh_06::HandleError) -> Self {
+        BaseviewError::Handle(err)
+    }
+}
+
+/// The message passed to `panic!`, if it was a string.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use super::*;
+
+    #[test]
+    fn panic_messages_are_recovered() {
+        let payload = panic::catch_unwind(|| panic!("missing {}", "plugin")).unwrap_err();
+        assert_eq!(panic_message(&*payload), "missing plugin");
+
+        let payload = panic::catch_unwind(|| panic!("static")).unwrap_err();
+        assert_eq!(panic_message(&*payload), "static");
+    }
+}
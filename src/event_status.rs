@@ -0,0 +1,141 @@
+use bevy::app::App;
+use bevy::ecs::system::{InRef, IntoSystem, Resource, SystemId};
+use bevy::prelude::{With, World};
+use bevy::window::{PrimaryWindow, Window};
+
+use crate::drag_drop::DropTarget;
+
+/// Decides whether keyboard events are captured by the editor or passed back to the host.
+///
+/// Passing an event back (`baseview::EventStatus::Ignored`) lets DAW shortcuts such as
+/// spacebar-to-play keep working while the editor has focus. Bevy still receives every event.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardCapturePolicy {
+    /// Capture keys only while a text field has focus, signalled by `Window::ime_enabled`
+    /// on the primary window.
+    #[default]
+    TextInputFocused,
+    /// Capture every keyboard event.
+    Always,
+    /// Pass every keyboard event back to the host.
+    Never,
+}
+
+/// A one-shot system asked for the status of every baseview event.
+///
+/// Returning `None` falls back to [`KeyboardCapturePolicy`] for keyboard events and to
+/// [`DropTarget`] for drag events.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct EventStatusHook(
+    pub SystemId<InRef<'static, baseview::Event>, Option<baseview::EventStatus>>,
+);
+
+pub trait BaseviewAppExt {
+    /// Registers `hook` as the [`EventStatusHook`], replacing any previous one.
+    fn set_event_status_hook<M>(
+        &mut self,
+        hook: impl IntoSystem<InRef<'static, baseview::Event>, Option<baseview::EventStatus>, M>
+            + 'static,
+    ) -> &mut Self;
+}
+
+impl BaseviewAppExt for App {
+    fn set_event_status_hook<M>(
+        &mut self,
+        hook: impl IntoSystem<InRef<'static, baseview::Event>, Option<baseview::EventStatus>, M>
+            + 'static,
+    ) -> &mut Self {
+        let world = self.world_mut();
+        if let Some(EventStatusHook(previous)) = world.remove_resource::<EventStatusHook>() {
+            let _ = world.unregister_system(previous);
+        }
+
+        let hook = world.register_system(hook);
+        world.insert_resource(EventStatusHook(hook));
+        self
+    }
+}
+
+/// The status `baseview::WindowHandler::on_event` should return for `event`.
+pub fn resolve(world: &mut World, event: &baseview::Event) -> baseview::EventStatus {
+    if let Some(EventStatusHook(hook)) = world.get_resource::<EventStatusHook>().copied() {
+        match world.run_system_with_input(hook, event) {
+            Ok(Some(status)) => return status,
+            Ok(None) => {}
+            Err(err) => log::warn!("Event status hook failed: {:?}", err),
+        }
+    }
+
+    match event {
+        baseview::Event::Keyboard(_) => {
+            let policy = world
+                .get_resource::<KeyboardCapturePolicy>()
+                .copied()
+                .unwrap_or_default();
+
+            let capture = match policy {
+                KeyboardCapturePolicy::TextInputFocused => world
+                    .query_filtered::<&Window, With<PrimaryWindow>>()
+                    .get_single(world)
+                    .is_ok_and(|window| window.ime_enabled),
+                KeyboardCapturePolicy::Always => true,
+                KeyboardCapturePolicy::Never => false,
+            };
+
+            if capture {
+                baseview::EventStatus::Captured
+            } else {
+                baseview::EventStatus::Ignored
+            }
+        }
+        baseview::Event::Mouse(
+            baseview::MouseEvent::DragEntered { .. }
+            | baseview::MouseEvent::DragMoved { .. }
+            | baseview::MouseEvent::DragDropped { .. },
+        ) => world
+            .get_resource::<DropTarget>()
+            .copied()
+            .unwrap_or_default()
+            .status(),
+        _ => baseview::EventStatus::Captured,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_event() -> baseview::Event {
+        baseview::Event::Keyboard(keyboard_types::KeyboardEvent::default())
+    }
+
+    #[test]
+    fn keys_are_captured_only_while_typing_by_default() {
+        let mut world = World::new();
+        let window = world.spawn((Window::default(), PrimaryWindow)).id();
+
+        assert_eq!(resolve(&mut world, &key_event()), baseview::EventStatus::Ignored);
+
+        world.get_mut::<Window>(window).unwrap().ime_enabled = true;
+        assert_eq!(resolve(&mut world, &key_event()), baseview::EventStatus::Captured);
+    }
+
+    #[test]
+    fn hook_overrides_policy() {
+        let mut app = App::new();
+        app.insert_resource(KeyboardCapturePolicy::Always);
+        app.set_event_status_hook(|InRef(event): InRef<baseview::Event>| match event {
+            baseview::Event::Keyboard(_) => Some(baseview::EventStatus::Ignored),
+            _ => None,
+        });
+
+        assert_eq!(
+            resolve(app.world_mut(), &key_event()),
+            baseview::EventStatus::Ignored
+        );
+        assert_eq!(
+            resolve(app.world_mut(), &baseview::Event::Window(baseview::WindowEvent::Focused)),
+            baseview::EventStatus::Captured
+        );
+    }
+}
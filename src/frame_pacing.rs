@@ -0,0 +1,161 @@
+use std::time::{Duration, Instant};
+
+use bevy::ecs::system::Resource;
+
+/// How much of the latest frame rate goes into [`FrameDiagnostics::fps`].
+const FPS_SMOOTHING: f64 = 0.1;
+
+/// Caps how often `BevyWindow` updates the app, independent of how often baseview calls
+/// `on_frame`. Applied before [`UpdateMode`](crate::UpdateMode).
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct FramePacing {
+    /// Most updates per second, e.g. 30 for low-CPU meter views. `None` updates on every
+    /// baseview frame.
+    pub fps_cap: Option<f64>,
+}
+
+impl FramePacing {
+    pub fn capped(fps_cap: f64) -> Self {
+        Self {
+            fps_cap: Some(fps_cap),
+        }
+    }
+
+    fn min_frame_time(&self) -> Duration {
+        self.fps_cap
+            .filter(|fps_cap| *fps_cap > 0.0)
+            .map_or(Duration::ZERO, |fps_cap| Duration::from_secs_f64(1.0 / fps_cap))
+    }
+}
+
+/// Frame timing measured by `BevyWindow`, refreshed right before every update.
+///
+/// The same frame time drives `Time`, unless the app set `TimeUpdateStrategy::ManualDuration`.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct FrameDiagnostics {
+    /// Time between the starts of the last two updates.
+    pub frame_time: Duration,
+    /// How long the previous `App::update` took.
+    pub update_time: Duration,
+    /// Updates per second, smoothed over recent frames.
+    pub fps: f64,
+    /// baseview frames skipped by [`FramePacing`] since the window opened.
+    pub skipped_frames: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct FramePacer {
+    last_start: Option<Instant>,
+    next_start: Option<Instant>,
+    diagnostics: FrameDiagnostics,
+}
+
+impl FramePacer {
+    /// Whether an update may start at `now`, counting the frame as skipped if not.
+    pub fn is_due(&mut self, now: Instant) -> bool {
+        let Some(next_start) = self.next_start else {
+            return true;
+        };
+
+        let due = now >= next_start;
+        if !due {
+            self.diagnostics.skipped_frames += 1;
+        }
+        due
+    }
+
+    /// Records an update starting at `now`, returning the diagnostics to publish for it.
+    ///
+    /// The next update is due one frame time after this one was, not after `now`, so host
+    /// frames arriving a little late or early do not cost whole frames. After falling behind
+    /// by more than a frame, the schedule restarts from `now`.
+    pub fn begin(&mut self, now: Instant, pacing: &FramePacing) -> FrameDiagnostics {
+        let min_frame_time = pacing.min_frame_time();
+        self.next_start = Some(match self.next_start {
+            Some(next_start) if now.saturating_duration_since(next_start) < min_frame_time => {
+                next_start + min_frame_time
+            }
+            _ => now + min_frame_time,
+        });
+
+        if let Some(last_start) = self.last_start {
+            let frame_time = now.saturating_duration_since(last_start);
+            self.diagnostics.frame_time = frame_time;
+
+            if !frame_time.is_zero() {
+                let fps = 1.0 / frame_time.as_secs_f64();
+                self.diagnostics.fps = if self.diagnostics.fps == 0.0 {
+                    fps
+                } else {
+                    self.diagnostics.fps + (fps - self.diagnostics.fps) * FPS_SMOOTHING
+                };
+            }
+        }
+        self.last_start = Some(now);
+
+        self.diagnostics
+    }
+
+    /// Records how long the update started by [`Self::begin`] took.
+    pub fn end(&mut self, update_time: Duration) {
+        self.diagnostics.update_time = update_time;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fps_cap_skips_early_frames() {
+        let pacing = FramePacing::capped(30.0);
+        let mut pacer = FramePacer::default();
+        let start = Instant::now();
+
+        assert!(pacer.is_due(start));
+        pacer.begin(start, &pacing);
+        pacer.end(Duration::from_millis(1));
+
+        assert!(!pacer.is_due(start + Duration::from_millis(16)));
+        assert!(pacer.is_due(start + Duration::from_millis(34)));
+        assert_eq!(pacer.diagnostics.skipped_frames, 1);
+
+        let diagnostics = pacer.begin(start + Duration::from_millis(40), &pacing);
+        assert_eq!(diagnostics.frame_time, Duration::from_millis(40));
+        assert_eq!(diagnostics.fps, 25.0);
+    }
+
+    #[test]
+    fn uncapped_updates_run_on_every_frame() {
+        let pacing = FramePacing::default();
+        let mut pacer = FramePacer::default();
+        let start = Instant::now();
+
+        pacer.begin(start, &pacing);
+        pacer.end(Duration::from_millis(50));
+
+        assert!(pacer.is_due(start + Duration::from_millis(50)));
+        assert_eq!(pacer.diagnostics.skipped_frames, 0);
+    }
+
+    #[test]
+    fn host_frame_jitter_keeps_the_capped_rate() {
+        let pacing = FramePacing::capped(30.0);
+        let mut pacer = FramePacer::default();
+        let start = Instant::now();
+
+        // One second of 60 Hz host frames, each up to 2 ms early or late.
+        let mut updates = 0;
+        for frame in 0..60u64 {
+            let jitter_us = (frame * 7 % 5) * 1000;
+            let now = start + Duration::from_micros(frame * 16_667 + jitter_us)
+                - Duration::from_millis(2);
+            if pacer.is_due(now) {
+                pacer.begin(now, &pacing);
+                updates += 1;
+            }
+        }
+
+        assert_eq!(updates, 30);
+    }
+}
@@ -0,0 +1,417 @@
+use std::sync::{Arc, Mutex};
+
+use bevy::app::{App, PostUpdate};
+use bevy::asset::{Assets, Handle, RenderAssetUsages};
+use bevy::ecs::schedule::IntoSystemConfigs;
+use bevy::ecs::system::Resource;
+use bevy::image::Image;
+use bevy::math::DVec2;
+use bevy::prelude::{Entity, Query, Res, ResMut, Trigger, With};
+use bevy::render::camera::{Camera, CameraUpdateSystem, RenderTarget};
+use bevy::render::gpu_readback::{Readback, ReadbackComplete};
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::window::{PrimaryWindow, Window, WindowRef};
+
+use crate::app_builder::BaseviewAppBuilder;
+use crate::error::BaseviewError;
+use crate::native_window::NativeWindow;
+use crate::window::BevyWindow;
+
+/// How many updates [`HeadlessWindow::read_pixels`] waits for the GPU readback.
+const MAX_READBACK_UPDATES: usize = 10;
+
+/// Bytes per pixel of the offscreen image, which is RGBA8 sRGB.
+const PIXEL_SIZE: usize = 4;
+
+/// The offscreen image that cameras targeting the primary window render into when running
+/// in a [`HeadlessWindow`].
+#[derive(Resource, Debug, Clone)]
+pub struct HeadlessRenderTarget(pub Handle<Image>);
+
+/// Runs an editor's app without a native window, e.g. for rendering tests in CI.
+///
+/// The app is built like in [`try_open_parented`](crate::try_open_parented), except that
+/// `post_init` hooks do not run. Cameras that target the primary window are redirected to
+/// a [`HeadlessRenderTarget`] image that follows the window size, and baseview events can
+/// be injected with [`send_event`](Self::send_event).
+///
+/// Rendering needs a wgpu adapter but no display. On a GPU-less Linux box, Mesa's software
+/// rasterizers are picked up on their own: llvmpipe through EGL, or lavapipe with
+/// `WGPU_BACKEND=vulkan`. Apps without `RenderPlugin` run headless too, just without pixels.
+pub struct HeadlessWindow {
+    window: BevyWindow,
+    offscreen_window: OffscreenWindow,
+    render_target: Option<Handle<Image>>,
+}
+
+impl HeadlessWindow {
+    /// Builds the app with a primary window of `window_open_options.size` and scale, which
+    /// `Startup` systems already see.
+    pub fn new(
+        window_open_options: baseview::WindowOpenOptions,
+        app_builder: impl Into<BaseviewAppBuilder>,
+    ) -> Result<Self, BaseviewError> {
+        let scale_factor = match window_open_options.scale {
+            baseview::WindowScalePolicy::ScaleFactor(scale_factor) => scale_factor,
+            baseview::WindowScalePolicy::SystemScaleFactor => 1.0,
+        };
+        let size = window_open_options.size;
+
+        let mut app = app_builder.into().build_headless(|app| {
+            let world = app.world_mut();
+            if let Ok(mut window) = world
+                .query_filtered::<&mut Window, With<PrimaryWindow>>()
+                .get_single_mut(world)
+            {
+                window.resolution.set_scale_factor(scale_factor as f32);
+                window.resolution.set(size.width as f32, size.height as f32);
+            }
+            add_render_target(app);
+        })?;
+
+        let world = app.world_mut();
+        world
+            .query_filtered::<(), With<PrimaryWindow>>()
+            .get_single(world)?;
+        let render_target = world
+            .get_resource::<HeadlessRenderTarget>()
+            .map(|render_target| render_target.0.clone());
+
+        Ok(Self {
+            window: BevyWindow::new(app),
+            offscreen_window: OffscreenWindow::default(),
+            render_target,
+        })
+    }
+
+    /// Feeds `event` to the app as baseview would. It takes effect on the next update.
+    pub fn send_event(&mut self, event: baseview::Event) -> baseview::EventStatus {
+        self.window.handle_event(event)
+    }
+
+    /// Runs one frame the way baseview's `on_frame` does: converts the events sent so far,
+    /// updates the app as `FramePacing` and `BaseviewSettings` allow, and applies window
+    /// changes.
+    ///
+    /// If the app asked to close, `WindowEvent::WillClose` follows as it would from baseview,
+    /// and the app is dropped.
+    pub fn update(&mut self) {
+        self.window.run_frame(&mut self.offscreen_window);
+
+        if self.offscreen_window.close_requested && !self.offscreen_window.closed {
+            self.offscreen_window.closed = true;
+            self.send_event(baseview::Event::Window(baseview::WindowEvent::WillClose));
+        }
+    }
+
+    pub fn app(&self) -> &App {
+        self.window.app()
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        self.window.app_mut()
+    }
+
+    /// The offscreen image, or `None` if the app has no `Assets<Image>` to render into.
+    pub fn render_target(&self) -> Option<&Handle<Image>> {
+        self.render_target.as_ref()
+    }
+
+    /// Updates the app until the GPU has copied the offscreen image back, returning its
+    /// pixels as tightly packed RGBA8 sRGB rows.
+    ///
+    /// Returns `None` without a render target or if the readback did not complete.
+    pub fn read_pixels(&mut self) -> Option<Vec<u8>> {
+        let render_target = self.render_target.clone()?;
+
+        let pixels = Arc::new(Mutex::new(None));
+        let readback_pixels = pixels.clone();
+        let readback = self
+            .app_mut()
+            .world_mut()
+            .spawn(Readback::texture(render_target.clone()))
+            .observe(move |trigger: Trigger<ReadbackComplete>| {
+                *readback_pixels.lock().unwrap() = Some(trigger.event().0.clone());
+            })
+            .id();
+
+        for _ in 0..MAX_READBACK_UPDATES {
+            self.update();
+            if pixels.lock().unwrap().is_some() {
+                break;
+            }
+        }
+
+        let world = self.app_mut().world_mut();
+        world.despawn(readback);
+
+        let pixels = pixels.lock().unwrap().take()?;
+        let size = world
+            .resource::<Assets<Image>>()
+            .get(&render_target)?
+            .size();
+        Some(unpad_rows(pixels, size.x as usize, size.y as usize))
+    }
+}
+
+/// Stands in for the baseview window. Resizes need no work, as the render target follows
+/// the primary window's size on its own.
+#[derive(Debug, Default)]
+struct OffscreenWindow {
+    close_requested: bool,
+    closed: bool,
+}
+
+impl NativeWindow for OffscreenWindow {
+    fn set_mouse_cursor(&mut self, _cursor: baseview::MouseCursor) {}
+
+    fn resize(&mut self, _size: baseview::Size) {}
+
+    fn close(&mut self) {
+        self.close_requested = true;
+    }
+
+    fn warp_cursor(&mut self, _position: DVec2, _scale_factor: f64) -> bool {
+        false
+    }
+}
+
+fn add_render_target(app: &mut App) {
+    let world = app.world_mut();
+    let Ok(window) = world
+        .query_filtered::<&Window, With<PrimaryWindow>>()
+        .get_single(world)
+    else {
+        return;
+    };
+    let size = window.physical_size();
+
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x.max(1),
+            height: size.y.max(1),
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage |= TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC;
+
+    let Some(mut images) = world.get_resource_mut::<Assets<Image>>() else {
+        return;
+    };
+    let render_target = images.add(image);
+    world.insert_resource(HeadlessRenderTarget(render_target));
+
+    app.add_systems(
+        PostUpdate,
+        (resize_render_target, retarget_cameras).before(CameraUpdateSystem),
+    );
+}
+
+fn resize_render_target(
+    render_target: Res<HeadlessRenderTarget>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let size = window.physical_size().max(bevy::math::UVec2::ONE);
+
+    if images
+        .get(&render_target.0)
+        .is_some_and(|image| image.size() != size)
+    {
+        if let Some(image) = images.get_mut(&render_target.0) {
+            image.resize(Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            });
+        }
+    }
+}
+
+fn retarget_cameras(
+    render_target: Res<HeadlessRenderTarget>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Camera>,
+) {
+    let primary_window = windows.get_single().ok();
+
+    for mut camera in &mut cameras {
+        let targets_primary_window = match camera.target {
+            RenderTarget::Window(WindowRef::Primary) => true,
+            RenderTarget::Window(WindowRef::Entity(window)) => Some(window) == primary_window,
+            _ => false,
+        };
+        if targets_primary_window {
+            camera.target = RenderTarget::Image(render_target.0.clone());
+        }
+    }
+}
+
+/// Drops the padding wgpu adds to every row of a texture copy.
+fn unpad_rows(pixels: Vec<u8>, width: usize, height: usize) -> Vec<u8> {
+    let row_size = width * PIXEL_SIZE;
+    if height == 0 || pixels.len() == row_size * height {
+        return pixels;
+    }
+
+    let padded_row_size = pixels.len() / height;
+    pixels
+        .chunks(padded_row_size)
+        .flat_map(|row| &row[..row_size])
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::KeyCode;
+    use bevy::input::{ButtonInput, InputPlugin};
+    use bevy::picking::DefaultPickingPlugins;
+    use bevy::prelude::{
+        AppExit, Camera2d, ClearColor, Color, Commands, EventWriter, Startup, Update,
+    };
+    use bevy::window::WindowPlugin;
+
+    use super::*;
+    use crate::DefaultBaseviewPlugins;
+
+    fn window_open_options() -> baseview::WindowOpenOptions {
+        baseview::WindowOpenOptions {
+            title: "Headless".to_string(),
+            size: baseview::Size::new(320.0, 240.0),
+            scale: baseview::WindowScalePolicy::ScaleFactor(2.0),
+            #[cfg(feature = "opengl")]
+            gl_config: None,
+        }
+    }
+
+    #[test]
+    fn injected_events_reach_the_app() {
+        let mut window = HeadlessWindow::new(
+            window_open_options(),
+            BaseviewAppBuilder::new(|app| app.add_plugins((InputPlugin, WindowPlugin::default()))),
+        )
+        .unwrap();
+        assert!(window.render_target().is_none());
+
+        window.send_event(baseview::Event::Keyboard(keyboard_types::KeyboardEvent {
+            state: keyboard_types::KeyState::Down,
+            code: keyboard_types::Code::Space,
+            key: keyboard_types::Key::Character(" ".to_string()),
+            ..Default::default()
+        }));
+        window.update();
+
+        let world = window.app_mut().world_mut();
+        assert!(world
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::Space));
+
+        let primary_size = world
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .single(world)
+            .physical_size();
+        assert_eq!(primary_size, bevy::math::UVec2::new(640, 480));
+
+        assert!(window.read_pixels().is_none());
+    }
+
+    #[test]
+    fn startup_systems_see_the_window_size() {
+        let startup_size = Arc::new(Mutex::new(None));
+        let size = startup_size.clone();
+
+        HeadlessWindow::new(
+            window_open_options(),
+            BaseviewAppBuilder::new(move |app| {
+                app.add_plugins((InputPlugin, WindowPlugin::default()))
+                    .add_systems(
+                        Startup,
+                        move |windows: Query<&Window, With<PrimaryWindow>>| {
+                            *size.lock().unwrap() = Some(windows.single().physical_size());
+                        },
+                    )
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            *startup_size.lock().unwrap(),
+            Some(bevy::math::UVec2::new(640, 480))
+        );
+    }
+
+    #[test]
+    fn app_exit_closes_the_window() {
+        let mut window = HeadlessWindow::new(
+            window_open_options(),
+            BaseviewAppBuilder::new(|app| {
+                app.add_plugins((InputPlugin, WindowPlugin::default()))
+                    .add_systems(Update, |mut exit: EventWriter<AppExit>| {
+                        exit.send(AppExit::Success);
+                    })
+            }),
+        )
+        .unwrap();
+
+        window.update();
+
+        assert!(window.offscreen_window.closed);
+        assert!(window.app().world().entities().is_empty());
+    }
+
+    #[test]
+    fn renders_the_primary_window_offscreen() {
+        let window_open_options = baseview::WindowOpenOptions {
+            size: baseview::Size::new(30.0, 20.0),
+            scale: baseview::WindowScalePolicy::ScaleFactor(1.0),
+            ..window_open_options()
+        };
+        let window = HeadlessWindow::new(
+            window_open_options,
+            BaseviewAppBuilder::new(|app| {
+                app.add_plugins((DefaultBaseviewPlugins, DefaultPickingPlugins))
+                    .insert_resource(ClearColor(Color::srgb_u8(32, 64, 128)))
+                    .add_systems(Startup, |mut commands: Commands| {
+                        commands.spawn(Camera2d);
+                    })
+            }),
+        );
+        let mut window = match window {
+            Err(BaseviewError::AppBuild(message))
+                if message.starts_with("Unable to find a GPU") =>
+            {
+                eprintln!("skipping, no wgpu adapter: {}", message);
+                return;
+            }
+            window => window.unwrap(),
+        };
+
+        let pixels = window.read_pixels().unwrap();
+        assert_eq!(pixels.len(), 30 * 20 * PIXEL_SIZE);
+        assert!(pixels
+            .chunks(PIXEL_SIZE)
+            .all(|pixel| pixel == [32, 64, 128, 255]));
+    }
+
+    #[test]
+    fn row_padding_is_removed() {
+        let mut pixels = Vec::new();
+        for row in 0..2u8 {
+            pixels.extend([row; 2 * PIXEL_SIZE]);
+            pixels.extend([0xff; 8]);
+        }
+
+        assert_eq!(
+            unpad_rows(pixels, 2, 2),
+            [[0u8; 2 * PIXEL_SIZE], [1u8; 2 * PIXEL_SIZE]].concat()
+        );
+    }
+}
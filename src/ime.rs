@@ -0,0 +1,185 @@
+use bevy::prelude::Entity;
+use bevy::window::Ime;
+use keyboard_types::{Key, KeyState, KeyboardEvent, Modifiers};
+
+/// The text a key press would insert into a text field, if any.
+///
+/// Shortcuts (Control or Meta held) and control characters produce no text.
+pub fn key_text(event: &KeyboardEvent) -> Option<&str> {
+    if event.state != KeyState::Down {
+        return None;
+    }
+    if event.modifiers.intersects(Modifiers::CONTROL | Modifiers::META) {
+        return None;
+    }
+
+    match &event.key {
+        Key::Character(text) if !text.chars().any(char::is_control) => Some(text),
+        _ => None,
+    }
+}
+
+/// Turns baseview keyboard events into Bevy [`Ime`] events.
+///
+/// baseview has no native IME support, so composition is reconstructed from
+/// dead keys and [`KeyboardEvent::is_composing`]: characters typed while composing
+/// become the preedit string, and the next regular character commits. Characters typed
+/// outside a composition only arrive as `KeyboardInput`, so text fields don't get them twice.
+#[derive(Debug, Default)]
+pub struct ImeState {
+    enabled: bool,
+    composing: bool,
+    preedit: String,
+}
+
+impl ImeState {
+    /// Follows `Window::ime_enabled`, returning the event announcing a change.
+    pub fn set_enabled(&mut self, window: Entity, enabled: bool) -> Option<Ime> {
+        if self.enabled == enabled {
+            return None;
+        }
+
+        self.enabled = enabled;
+        self.composing = false;
+        self.preedit.clear();
+
+        Some(if enabled {
+            Ime::Enabled { window }
+        } else {
+            Ime::Disabled { window }
+        })
+    }
+
+    /// The events for a key press. Keys outside a composition produce none; keys that do
+    /// produce some are part of the composed text and should not be typed otherwise.
+    pub fn process(&mut self, window: Entity, event: &KeyboardEvent) -> Vec<Ime> {
+        let mut events = Vec::new();
+
+        if !self.enabled || event.state != KeyState::Down {
+            return events;
+        }
+
+        match &event.key {
+            Key::Dead => {
+                self.composing = true;
+                events.push(self.preedit_event(window));
+            }
+            Key::Backspace if self.composing => {
+                self.preedit.pop();
+                if self.preedit.is_empty() {
+                    self.composing = false;
+                }
+                events.push(self.preedit_event(window));
+            }
+            Key::Escape if self.composing => {
+                self.composing = false;
+                self.preedit.clear();
+                events.push(self.preedit_event(window));
+            }
+            _ => {
+                if let Some(text) = key_text(event) {
+                    if event.is_composing {
+                        self.composing = true;
+                        self.preedit.push_str(text);
+                        events.push(self.preedit_event(window));
+                    } else if self.composing {
+                        self.composing = false;
+                        self.preedit.clear();
+
+                        events.push(Ime::Commit {
+                            window,
+                            value: text.to_string(),
+                        });
+                        events.push(self.preedit_event(window));
+                    }
+                }
+            }
+        }
+
+        events
+    }
+
+    fn preedit_event(&self, window: Entity) -> Ime {
+        let cursor = self
+            .composing
+            .then_some((self.preedit.len(), self.preedit.len()));
+
+        Ime::Preedit {
+            window,
+            value: self.preedit.clone(),
+            cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_down(key: Key) -> KeyboardEvent {
+        KeyboardEvent {
+            state: KeyState::Down,
+            key,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn shortcuts_produce_no_text() {
+        let mut event = key_down(Key::Character("c".to_string()));
+        assert_eq!(key_text(&event), Some("c"));
+
+        event.modifiers = Modifiers::CONTROL;
+        assert_eq!(key_text(&event), None);
+    }
+
+    #[test]
+    fn disabled_ime_emits_nothing() {
+        let window = Entity::from_raw(0);
+        let mut ime = ImeState::default();
+
+        assert!(ime.process(window, &key_down(Key::Character("a".to_string()))).is_empty());
+    }
+
+    #[test]
+    fn plain_characters_are_not_committed() {
+        let window = Entity::from_raw(0);
+        let mut ime = ImeState::default();
+        ime.set_enabled(window, true);
+
+        assert!(ime.process(window, &key_down(Key::Character("a".to_string()))).is_empty());
+    }
+
+    #[test]
+    fn dead_key_composes_then_commits() {
+        let window = Entity::from_raw(0);
+        let mut ime = ImeState::default();
+        assert_eq!(ime.set_enabled(window, true), Some(Ime::Enabled { window }));
+
+        let events = ime.process(window, &key_down(Key::Dead));
+        assert_eq!(
+            events,
+            vec![Ime::Preedit {
+                window,
+                value: String::new(),
+                cursor: Some((0, 0)),
+            }]
+        );
+
+        let events = ime.process(window, &key_down(Key::Character("é".to_string())));
+        assert_eq!(
+            events,
+            vec![
+                Ime::Commit {
+                    window,
+                    value: "é".to_string(),
+                },
+                Ime::Preedit {
+                    window,
+                    value: String::new(),
+                    cursor: None,
+                },
+            ]
+        );
+    }
+}
@@ -1,301 +1,215 @@
-use bevy::input::keyboard::{Key, KeyCode as BevyKeyCode};
+use bevy::input::keyboard::{Key, KeyCode as BevyKeyCode, NativeKeyCode};
 
-pub fn key_to_keycode(key: keyboard_types::Key) -> BevyKeyCode {
-    match key {
-        // keyboard_types::Key::AVRInput => BevyKeyCode::TODO,
-        // keyboard_types::Key::AVRPower => BevyKeyCode::TODO,
-        // keyboard_types::Key::Accept => BevyKeyCode::TODO,
-        // keyboard_types::Key::Again => BevyKeyCode::TODO,
-        // keyboard_types::Key::AllCandidates => BevyKeyCode::TODO,
-        // keyboard_types::Key::Alphanumeric => BevyKeyCode::TODO,
-        keyboard_types::Key::Alt => BevyKeyCode::AltRight, // TODO: Less accurate.
-        // keyboard_types::Key::AltGraph => BevyKeyCode::TODO,
-        // keyboard_types::Key::AppSwitch => BevyKeyCode::TODO,
-        keyboard_types::Key::ArrowDown => BevyKeyCode::ArrowDown,
-        keyboard_types::Key::ArrowLeft => BevyKeyCode::ArrowLeft,
-        keyboard_types::Key::ArrowRight => BevyKeyCode::ArrowRight,
-        keyboard_types::Key::ArrowUp => BevyKeyCode::ArrowUp,
-        // keyboard_types::Key::Attn => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioBalanceLeft => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioBalanceRight => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioBassBoostDown => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioBassBoostToggle => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioBassBoostUp => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioFaderFront => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioFaderRear => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioSurroundModeNext => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioTrebleDown => BevyKeyCode::TODO,
-        // keyboard_types::Key::AudioTrebleUp => BevyKeyCode::TODO,
-        keyboard_types::Key::AudioVolumeDown => BevyKeyCode::AudioVolumeDown,
-        keyboard_types::Key::AudioVolumeMute => BevyKeyCode::AudioVolumeMute,
-        keyboard_types::Key::AudioVolumeUp => BevyKeyCode::AudioVolumeUp,
-        keyboard_types::Key::Backspace => BevyKeyCode::Backspace,
-        // keyboard_types::Key::BrightnessDown => BevyKeyCode::TODO,
-        // keyboard_types::Key::BrightnessUp => BevyKeyCode::TODO,
-        keyboard_types::Key::BrowserBack => BevyKeyCode::BrowserBack,
-        keyboard_types::Key::BrowserFavorites => BevyKeyCode::BrowserFavorites,
-        keyboard_types::Key::BrowserForward => BevyKeyCode::BrowserForward,
-        keyboard_types::Key::BrowserHome => BevyKeyCode::BrowserHome,
-        keyboard_types::Key::BrowserRefresh => BevyKeyCode::BrowserRefresh,
-        keyboard_types::Key::BrowserSearch => BevyKeyCode::BrowserSearch,
-        keyboard_types::Key::BrowserStop => BevyKeyCode::BrowserStop,
-        // keyboard_types::Key::Call => BevyKeyCode::TODO,
-        // keyboard_types::Key::Camera => BevyKeyCode::TODO,
-        // keyboard_types::Key::CameraFocus => BevyKeyCode::TODO,
-        // keyboard_types::Key::Cancel => BevyKeyCode::TODO,
-        // keyboard_types::Key::CapsLock => BevyKeyCode::TODO,
-        // keyboard_types::Key::ChannelDown => BevyKeyCode::TODO,
-        // keyboard_types::Key::ChannelUp => BevyKeyCode::TODO,
-        keyboard_types::Key::Character(s) => match s.as_str() {
-            "A" | "a" => BevyKeyCode::KeyA,
-            "B" | "b" => BevyKeyCode::KeyB,
-            "C" | "c" => BevyKeyCode::KeyC,
-            "D" | "d" => BevyKeyCode::KeyD,
-            "E" | "e" => BevyKeyCode::KeyE,
-            "F" | "f" => BevyKeyCode::KeyF,
-            "G" | "g" => BevyKeyCode::KeyG,
-            "H" | "h" => BevyKeyCode::KeyH,
-            "I" | "i" => BevyKeyCode::KeyI,
-            "J" | "j" => BevyKeyCode::KeyJ,
-            "K" | "k" => BevyKeyCode::KeyK,
-            "L" | "l" => BevyKeyCode::KeyL,
-            "M" | "m" => BevyKeyCode::KeyM,
-            "N" | "n" => BevyKeyCode::KeyN,
-            "O" | "o" => BevyKeyCode::KeyO,
-            "P" | "p" => BevyKeyCode::KeyP,
-            "Q" | "q" => BevyKeyCode::KeyQ,
-            "R" | "r" => BevyKeyCode::KeyR,
-            "S" | "s" => BevyKeyCode::KeyS,
-            "T" | "t" => BevyKeyCode::KeyT,
-            "U" | "u" => BevyKeyCode::KeyU,
-            "V" | "v" => BevyKeyCode::KeyV,
-            "W" | "w" => BevyKeyCode::KeyW,
-            "X" | "x" => BevyKeyCode::KeyX,
-            "Y" | "y" => BevyKeyCode::KeyY,
-            "Z" | "z" => BevyKeyCode::KeyZ,
-            " " => BevyKeyCode::Space,
-            "-" => BevyKeyCode::Minus,
-            "0" => BevyKeyCode::Digit0,
-            "1" => BevyKeyCode::Digit1,
-            "2" => BevyKeyCode::Digit2,
-            "3" => BevyKeyCode::Digit3,
-            "4" => BevyKeyCode::Digit4,
-            "5" => BevyKeyCode::Digit5,
-            "6" => BevyKeyCode::Digit6,
-            "7" => BevyKeyCode::Digit7,
-            "8" => BevyKeyCode::Digit8,
-            "9" => BevyKeyCode::Digit9,
-            ";" => BevyKeyCode::Semicolon,
-            "/" => BevyKeyCode::Slash,
-            "," => BevyKeyCode::Comma,
-            "." => BevyKeyCode::Period,
-            "=" => BevyKeyCode::Equal,
-            "'" => BevyKeyCode::Quote,
-            "[" => BevyKeyCode::BracketLeft,
-            "]" => BevyKeyCode::BracketRight,
-            _ => BevyKeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified)
-        },
-        // keyboard_types::Key::Clear => BevyKeyCode::TODO,
-        // keyboard_types::Key::Close => BevyKeyCode::TODO,
-        // keyboard_types::Key::ClosedCaptionToggle => BevyKeyCode::TODO,
-        // keyboard_types::Key::CodeInput => BevyKeyCode::TODO,
-        // keyboard_types::Key::ColorF0Red => BevyKeyCode::TODO,
-        // keyboard_types::Key::ColorF1Green => BevyKeyCode::TODO,
-        // keyboard_types::Key::ColorF2Yellow => BevyKeyCode::TODO,
-        // keyboard_types::Key::ColorF3Blue => BevyKeyCode::TODO,
-        // keyboard_types::Key::ColorF4Grey => BevyKeyCode::TODO,
-        // keyboard_types::Key::ColorF5Brown => BevyKeyCode::TODO,
-        //keyboard_types::Key::Compose => BevyKeyCode::TODO,
-        // keyboard_types::Key::ContextMenu => BevyKeyCode::TODO,
-        // keyboard_types::Key::Control => BevyKeyCode::TODO,
-        // keyboard_types::Key::Convert => BevyKeyCode::TODO,
-        keyboard_types::Key::Copy => BevyKeyCode::Copy,
-        // keyboard_types::Key::CrSel => BevyKeyCode::TODO,
-        keyboard_types::Key::Cut => BevyKeyCode::Cut,
-        // keyboard_types::Key::DVR => BevyKeyCode::TODO,
-        // keyboard_types::Key::Dead => BevyKeyCode::TODO,
-        keyboard_types::Key::Delete => BevyKeyCode::Delete,
-        // keyboard_types::Key::Dimmer => BevyKeyCode::TODO,
-        // keyboard_types::Key::DisplaySwap => BevyKeyCode::TODO,
-        // keyboard_types::Key::Eisu => BevyKeyCode::TODO,
-        // keyboard_types::Key::Eject => BevyKeyCode::TODO,
-        keyboard_types::Key::End => BevyKeyCode::End,
-        // keyboard_types::Key::EndCall => BevyKeyCode::TODO,
-        keyboard_types::Key::Enter => BevyKeyCode::Enter,
-        // keyboard_types::Key::EraseEof => BevyKeyCode::TODO,
-        keyboard_types::Key::Escape => BevyKeyCode::Escape,
-        // keyboard_types::Key::ExSel => BevyKeyCode::TODO,
-        // keyboard_types::Key::Execute => BevyKeyCode::TODO,
-        // keyboard_types::Key::Exit => BevyKeyCode::TODO,
-        keyboard_types::Key::F1 => BevyKeyCode::F1,
-        keyboard_types::Key::F10 => BevyKeyCode::F10,
-        keyboard_types::Key::F11 => BevyKeyCode::F11,
-        keyboard_types::Key::F12 => BevyKeyCode::F12,
-        keyboard_types::Key::F13 => BevyKeyCode::F13,
-        keyboard_types::Key::F14 => BevyKeyCode::F14,
-        keyboard_types::Key::F15 => BevyKeyCode::F15,
-        keyboard_types::Key::F16 => BevyKeyCode::F16,
-        keyboard_types::Key::F17 => BevyKeyCode::F17,
-        keyboard_types::Key::F18 => BevyKeyCode::F18,
-        keyboard_types::Key::F19 => BevyKeyCode::F19,
-        keyboard_types::Key::F2 => BevyKeyCode::F2,
-        keyboard_types::Key::F20 => BevyKeyCode::F20,
-        keyboard_types::Key::F21 => BevyKeyCode::F21,
-        keyboard_types::Key::F22 => BevyKeyCode::F22,
-        keyboard_types::Key::F23 => BevyKeyCode::F23,
-        keyboard_types::Key::F24 => BevyKeyCode::F24,
-        keyboard_types::Key::F3 => BevyKeyCode::F3,
-        keyboard_types::Key::F4 => BevyKeyCode::F4,
-        keyboard_types::Key::F5 => BevyKeyCode::F5,
-        keyboard_types::Key::F6 => BevyKeyCode::F6,
-        keyboard_types::Key::F7 => BevyKeyCode::F7,
-        keyboard_types::Key::F8 => BevyKeyCode::F8,
-        keyboard_types::Key::F9 => BevyKeyCode::F9,
-        // keyboard_types::Key::FavoriteClear0 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteClear1 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteClear2 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteClear3 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteRecall0 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteRecall1 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteRecall2 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteRecall3 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteStore0 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteStore1 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteStore2 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FavoriteStore3 => BevyKeyCode::TODO,
-        // keyboard_types::Key::FinalMode => BevyKeyCode::TODO,
-        // keyboard_types::Key::Find => BevyKeyCode::TODO,
-        // keyboard_types::Key::Fn => BevyKeyCode::TODO,
-        // keyboard_types::Key::FnLock => BevyKeyCode::TODO,
-        // keyboard_types::Key::GoBack => BevyKeyCode::TODO,
-        // keyboard_types::Key::GoHome => BevyKeyCode::TODO,
-        // keyboard_types::Key::GroupFirst => BevyKeyCode::TODO,
-        // keyboard_types::Key::GroupLast => BevyKeyCode::TODO,
-        // keyboard_types::Key::GroupNext => BevyKeyCode::TODO,
-        // keyboard_types::Key::GroupPrevious => BevyKeyCode::TODO,
-        // keyboard_types::Key::Guide => BevyKeyCode::TODO,
-        // keyboard_types::Key::GuideNextDay => BevyKeyCode::TODO,
-        // keyboard_types::Key::GuidePreviousDay => BevyKeyCode::TODO,
-        // keyboard_types::Key::HangulMode => BevyKeyCode::TODO,
-        // keyboard_types::Key::HanjaMode => BevyKeyCode::TODO,
-        // keyboard_types::Key::Hankaku => BevyKeyCode::TODO,
-        // keyboard_types::Key::HeadsetHook => BevyKeyCode::TODO,
-        // keyboard_types::Key::Help => BevyKeyCode::TODO,
-        // keyboard_types::Key::Hibernate => BevyKeyCode::TODO,
-        // keyboard_types::Key::Hiragana => BevyKeyCode::TODO,
-        // keyboard_types::Key::HiraganaKatakana => BevyKeyCode::TODO,
-        keyboard_types::Key::Home => BevyKeyCode::Home,
-        // keyboard_types::Key::Hyper => BevyKeyCode::TODO,
-        // keyboard_types::Key::Info => BevyKeyCode::TODO,
-        keyboard_types::Key::Insert => BevyKeyCode::Insert,
-        // keyboard_types::Key::InstantReplay => BevyKeyCode::TODO,
-        // keyboard_types::Key::JunjaMode => BevyKeyCode::TODO,
-        // keyboard_types::Key::KanaMode => BevyKeyCode::TODO,
-        // keyboard_types::Key::KanjiMode => BevyKeyCode::TODO,
-        // keyboard_types::Key::Katakana => BevyKeyCode::TODO,
-        // keyboard_types::Key::Key11 => BevyKeyCode::TODO,
-        // keyboard_types::Key::Key12 => BevyKeyCode::TODO,
-        // keyboard_types::Key::LastNumberRedial => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchApplication1 => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchApplication2 => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchCalendar => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchContacts => BevyKeyCode::TODO,
-        keyboard_types::Key::LaunchMail => BevyKeyCode::LaunchMail,
-        // keyboard_types::Key::LaunchMediaPlayer => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchMusicPlayer => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchPhone => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchScreenSaver => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchSpreadsheet => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchWebBrowser => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchWebCam => BevyKeyCode::TODO,
-        // keyboard_types::Key::LaunchWordProcessor => BevyKeyCode::TODO,
-        // keyboard_types::Key::Link => BevyKeyCode::TODO,
-        // keyboard_types::Key::ListProgram => BevyKeyCode::TODO,
-        // keyboard_types::Key::LiveContent => BevyKeyCode::TODO,
-        // keyboard_types::Key::Lock => BevyKeyCode::TODO,
-        // keyboard_types::Key::LogOff => BevyKeyCode::TODO,
-        // keyboard_types::Key::MailForward => BevyKeyCode::TODO,
-        // keyboard_types::Key::MailReply => BevyKeyCode::TODO,
-        // keyboard_types::Key::MailSend => BevyKeyCode::TODO,
-        // keyboard_types::Key::MannerMode => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaApps => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaAudioTrack => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaClose => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaFastForward => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaLast => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaPause => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaPlay => BevyKeyCode::TODO,
-        keyboard_types::Key::MediaPlayPause => BevyKeyCode::MediaPlayPause,
-        // keyboard_types::Key::MediaRecord => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaRewind => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaSkipBackward => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaSkipForward => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaStepBackward => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaStepForward => BevyKeyCode::TODO,
-        keyboard_types::Key::MediaStop => BevyKeyCode::MediaStop,
-        // keyboard_types::Key::MediaTopMenu => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaTrackNext => BevyKeyCode::TODO,
-        // keyboard_types::Key::MediaTrackPrevious => BevyKeyCode::TODO,
-        // keyboard_types::Key::Meta => BevyKeyCode::TODO,
-        // keyboard_types::Key::MicrophoneToggle => BevyKeyCode::TODO,
-        // keyboard_types::Key::MicrophoneVolumeDown => BevyKeyCode::TODO,
-        // keyboard_types::Key::MicrophoneVolumeMute => BevyKeyCode::TODO,
-        // keyboard_types::Key::MicrophoneVolumeUp => BevyKeyCode::TODO,
-        // keyboard_types::Key::ModeChange => BevyKeyCode::TODO,
-        // keyboard_types::Key::NavigateIn => BevyKeyCode::TODO,
-        // keyboard_types::Key::NavigateNext => BevyKeyCode::TODO,
-        // keyboard_types::Key::NavigateOut => BevyKeyCode::TODO,
-        // keyboard_types::Key::NavigatePrevious => BevyKeyCode::TODO,
-        // keyboard_types::Key::New => BevyKeyCode::TODO,
-        // keyboard_types::Key::NextCandidate => BevyKeyCode::TODO,
-        // keyboard_types::Key::NextFavoriteChannel => BevyKeyCode::TODO,
-        // keyboard_types::Key::NextUserProfile => BevyKeyCode::TODO,
-        // keyboard_types::Key::NonConvert => BevyKeyCode::TODO,
-        // keyboard_types::Key::Notification => BevyKeyCode::TODO,
-        keyboard_types::Key::NumLock => BevyKeyCode::NumLock,
-        // keyboard_types::Key::OnDemand => BevyKeyCode::TODO,
-        // keyboard_types::Key::Open => BevyKeyCode::TODO,
-        keyboard_types::Key::PageDown => BevyKeyCode::PageDown,
-        keyboard_types::Key::PageUp => BevyKeyCode::PageUp,
-        // keyboard_types::Key::Pairing => BevyKeyCode::TODO,
-        keyboard_types::Key::Paste => BevyKeyCode::Paste,
-        keyboard_types::Key::Pause => BevyKeyCode::Pause,
-        // keyboard_types::Key::PinPDown => BevyKeyCode::TODO,
-        // keyboard_types::Key::PinPMove => BevyKeyCode::TODO,
-        // keyboard_types::Key::PinPToggle => BevyKeyCode::TODO,
-        // keyboard_types::Key::PinPUp => BevyKeyCode::TODO,
-        // keyboard_types::Key::Play => BevyKeyCode::TODO,
-        // keyboard_types::Key::PlaySpeedDown => BevyKeyCode::TODO,
-        // keyboard_types::Key::PlaySpeedReset => BevyKeyCode::TODO,
-        // keyboard_types::Key::PlaySpeedUp => BevyKeyCode::TODO,
-        keyboard_types::Key::Power => BevyKeyCode::Power,
-        // keyboard_types::Key::PowerOff => BevyKeyCode::TODO,
-        // keyboard_types::Key::PreviousCandidate => BevyKeyCode::TODO,
-        // keyboard_types::Key::Print => BevyKeyCode::TODO,
-        keyboard_types::Key::PrintScreen => BevyKeyCode::PrintScreen,
-        // keyboard_types::Key::Process => BevyKeyCode::TODO,
-        // keyboard_types::Key::Props => BevyKeyCode::TODO,
-        // keyboard_types::Key::RandomToggle => BevyKeyCode::TODO,
-        // keyboard_types::Key::RcLowBattery => BevyKeyCode::TODO,
-        // keyboard_types::Key::RecordSpeedNext => BevyKeyCode::TODO,
-        // keyboard_types::Key::Redo => BevyKeyCode::TODO,
-        // keyboard_types::Key::RfBypass => BevyKeyCode::TODO,
-        // keyboard_types::Key::Romaji => BevyKeyCode::TODO,
-        // keyboard_types::Key::STBInput => BevyKeyCode::TODO,
-        // keyboard_types::Key::STBPower => BevyKeyCode::TODO,
-        // keyboard_types::Key::Save => BevyKeyCode::TODO,
-        // keyboard_types::Key::ScanChannelsToggle => BevyKeyCode::TODO,
-        // keyboard_types::Key::ScreenModeNext => BevyKeyCode::TODO,
-        keyboard_types::Key::ScrollLock => BevyKeyCode::ScrollLock,
-        // keyboard_types::Key::Select => BevyKeyCode::TODO,
-        // keyboard_types::Key::Settings => BevyKeyCode::TODO,
-        keyboard_types::Key::Shift => BevyKeyCode::ShiftLeft,
-        // keyboard_types::Key::SingleCandidate => BevyKeyCode::TODO,
-        keyboard_types::Key::Super => BevyKeyCode::SuperLeft, // TODO:
-        // keyboard_types::Key::Symbol => BevyKeyCode::TODO,
-        // keyboard_types::Key::SymbolLock => BevyKeyCode::TODO,
-        keyboard_types::Key::Tab => BevyKeyCode::Tab,
-        _ => BevyKeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified)
+/// Translates the physical key position reported by baseview into Bevy's [`BevyKeyCode`].
+///
+/// Both enums follow the UI Events `code` specification, so this is layout independent:
+/// the key left of Tab is always [`BevyKeyCode::KeyQ`], whatever character it produces.
+pub fn code_to_keycode(code: keyboard_types::Code) -> BevyKeyCode {
+    match code {
+        keyboard_types::Code::Backquote => BevyKeyCode::Backquote,
+        keyboard_types::Code::Backslash => BevyKeyCode::Backslash,
+        keyboard_types::Code::BracketLeft => BevyKeyCode::BracketLeft,
+        keyboard_types::Code::BracketRight => BevyKeyCode::BracketRight,
+        keyboard_types::Code::Comma => BevyKeyCode::Comma,
+        keyboard_types::Code::Digit0 => BevyKeyCode::Digit0,
+        keyboard_types::Code::Digit1 => BevyKeyCode::Digit1,
+        keyboard_types::Code::Digit2 => BevyKeyCode::Digit2,
+        keyboard_types::Code::Digit3 => BevyKeyCode::Digit3,
+        keyboard_types::Code::Digit4 => BevyKeyCode::Digit4,
+        keyboard_types::Code::Digit5 => BevyKeyCode::Digit5,
+        keyboard_types::Code::Digit6 => BevyKeyCode::Digit6,
+        keyboard_types::Code::Digit7 => BevyKeyCode::Digit7,
+        keyboard_types::Code::Digit8 => BevyKeyCode::Digit8,
+        keyboard_types::Code::Digit9 => BevyKeyCode::Digit9,
+        keyboard_types::Code::Equal => BevyKeyCode::Equal,
+        keyboard_types::Code::IntlBackslash => BevyKeyCode::IntlBackslash,
+        keyboard_types::Code::IntlRo => BevyKeyCode::IntlRo,
+        keyboard_types::Code::IntlYen => BevyKeyCode::IntlYen,
+        keyboard_types::Code::KeyA => BevyKeyCode::KeyA,
+        keyboard_types::Code::KeyB => BevyKeyCode::KeyB,
+        keyboard_types::Code::KeyC => BevyKeyCode::KeyC,
+        keyboard_types::Code::KeyD => BevyKeyCode::KeyD,
+        keyboard_types::Code::KeyE => BevyKeyCode::KeyE,
+        keyboard_types::Code::KeyF => BevyKeyCode::KeyF,
+        keyboard_types::Code::KeyG => BevyKeyCode::KeyG,
+        keyboard_types::Code::KeyH => BevyKeyCode::KeyH,
+        keyboard_types::Code::KeyI => BevyKeyCode::KeyI,
+        keyboard_types::Code::KeyJ => BevyKeyCode::KeyJ,
+        keyboard_types::Code::KeyK => BevyKeyCode::KeyK,
+        keyboard_types::Code::KeyL => BevyKeyCode::KeyL,
+        keyboard_types::Code::KeyM => BevyKeyCode::KeyM,
+        keyboard_types::Code::KeyN => BevyKeyCode::KeyN,
+        keyboard_types::Code::KeyO => BevyKeyCode::KeyO,
+        keyboard_types::Code::KeyP => BevyKeyCode::KeyP,
+        keyboard_types::Code::KeyQ => BevyKeyCode::KeyQ,
+        keyboard_types::Code::KeyR => BevyKeyCode::KeyR,
+        keyboard_types::Code::KeyS => BevyKeyCode::KeyS,
+        keyboard_types::Code::KeyT => BevyKeyCode::KeyT,
+        keyboard_types::Code::KeyU => BevyKeyCode::KeyU,
+        keyboard_types::Code::KeyV => BevyKeyCode::KeyV,
+        keyboard_types::Code::KeyW => BevyKeyCode::KeyW,
+        keyboard_types::Code::KeyX => BevyKeyCode::KeyX,
+        keyboard_types::Code::KeyY => BevyKeyCode::KeyY,
+        keyboard_types::Code::KeyZ => BevyKeyCode::KeyZ,
+        keyboard_types::Code::Minus => BevyKeyCode::Minus,
+        keyboard_types::Code::Period => BevyKeyCode::Period,
+        keyboard_types::Code::Quote => BevyKeyCode::Quote,
+        keyboard_types::Code::Semicolon => BevyKeyCode::Semicolon,
+        keyboard_types::Code::Slash => BevyKeyCode::Slash,
+        keyboard_types::Code::AltLeft => BevyKeyCode::AltLeft,
+        keyboard_types::Code::AltRight => BevyKeyCode::AltRight,
+        keyboard_types::Code::Backspace => BevyKeyCode::Backspace,
+        keyboard_types::Code::CapsLock => BevyKeyCode::CapsLock,
+        keyboard_types::Code::ContextMenu => BevyKeyCode::ContextMenu,
+        keyboard_types::Code::ControlLeft => BevyKeyCode::ControlLeft,
+        keyboard_types::Code::ControlRight => BevyKeyCode::ControlRight,
+        keyboard_types::Code::Enter => BevyKeyCode::Enter,
+        keyboard_types::Code::MetaLeft => BevyKeyCode::SuperLeft,
+        keyboard_types::Code::MetaRight => BevyKeyCode::SuperRight,
+        keyboard_types::Code::ShiftLeft => BevyKeyCode::ShiftLeft,
+        keyboard_types::Code::ShiftRight => BevyKeyCode::ShiftRight,
+        keyboard_types::Code::Space => BevyKeyCode::Space,
+        keyboard_types::Code::Tab => BevyKeyCode::Tab,
+        keyboard_types::Code::Convert => BevyKeyCode::Convert,
+        keyboard_types::Code::KanaMode => BevyKeyCode::KanaMode,
+        keyboard_types::Code::Lang1 => BevyKeyCode::Lang1,
+        keyboard_types::Code::Lang2 => BevyKeyCode::Lang2,
+        keyboard_types::Code::Lang3 => BevyKeyCode::Lang3,
+        keyboard_types::Code::Lang4 => BevyKeyCode::Lang4,
+        keyboard_types::Code::Lang5 => BevyKeyCode::Lang5,
+        keyboard_types::Code::NonConvert => BevyKeyCode::NonConvert,
+        keyboard_types::Code::Delete => BevyKeyCode::Delete,
+        keyboard_types::Code::End => BevyKeyCode::End,
+        keyboard_types::Code::Help => BevyKeyCode::Help,
+        keyboard_types::Code::Home => BevyKeyCode::Home,
+        keyboard_types::Code::Insert => BevyKeyCode::Insert,
+        keyboard_types::Code::PageDown => BevyKeyCode::PageDown,
+        keyboard_types::Code::PageUp => BevyKeyCode::PageUp,
+        keyboard_types::Code::ArrowDown => BevyKeyCode::ArrowDown,
+        keyboard_types::Code::ArrowLeft => BevyKeyCode::ArrowLeft,
+        keyboard_types::Code::ArrowRight => BevyKeyCode::ArrowRight,
+        keyboard_types::Code::ArrowUp => BevyKeyCode::ArrowUp,
+        keyboard_types::Code::NumLock => BevyKeyCode::NumLock,
+        keyboard_types::Code::Numpad0 => BevyKeyCode::Numpad0,
+        keyboard_types::Code::Numpad1 => BevyKeyCode::Numpad1,
+        keyboard_types::Code::Numpad2 => BevyKeyCode::Numpad2,
+        keyboard_types::Code::Numpad3 => BevyKeyCode::Numpad3,
+        keyboard_types::Code::Numpad4 => BevyKeyCode::Numpad4,
+        keyboard_types::Code::Numpad5 => BevyKeyCode::Numpad5,
+        keyboard_types::Code::Numpad6 => BevyKeyCode::Numpad6,
+        keyboard_types::Code::Numpad7 => BevyKeyCode::Numpad7,
+        keyboard_types::Code::Numpad8 => BevyKeyCode::Numpad8,
+        keyboard_types::Code::Numpad9 => BevyKeyCode::Numpad9,
+        keyboard_types::Code::NumpadAdd => BevyKeyCode::NumpadAdd,
+        keyboard_types::Code::NumpadBackspace => BevyKeyCode::NumpadBackspace,
+        keyboard_types::Code::NumpadClear => BevyKeyCode::NumpadClear,
+        keyboard_types::Code::NumpadClearEntry => BevyKeyCode::NumpadClearEntry,
+        keyboard_types::Code::NumpadComma => BevyKeyCode::NumpadComma,
+        keyboard_types::Code::NumpadDecimal => BevyKeyCode::NumpadDecimal,
+        keyboard_types::Code::NumpadDivide => BevyKeyCode::NumpadDivide,
+        keyboard_types::Code::NumpadEnter => BevyKeyCode::NumpadEnter,
+        keyboard_types::Code::NumpadEqual => BevyKeyCode::NumpadEqual,
+        keyboard_types::Code::NumpadHash => BevyKeyCode::NumpadHash,
+        keyboard_types::Code::NumpadMemoryAdd => BevyKeyCode::NumpadMemoryAdd,
+        keyboard_types::Code::NumpadMemoryClear => BevyKeyCode::NumpadMemoryClear,
+        keyboard_types::Code::NumpadMemoryRecall => BevyKeyCode::NumpadMemoryRecall,
+        keyboard_types::Code::NumpadMemoryStore => BevyKeyCode::NumpadMemoryStore,
+        keyboard_types::Code::NumpadMemorySubtract => BevyKeyCode::NumpadMemorySubtract,
+        keyboard_types::Code::NumpadMultiply => BevyKeyCode::NumpadMultiply,
+        keyboard_types::Code::NumpadParenLeft => BevyKeyCode::NumpadParenLeft,
+        keyboard_types::Code::NumpadParenRight => BevyKeyCode::NumpadParenRight,
+        keyboard_types::Code::NumpadStar => BevyKeyCode::NumpadStar,
+        keyboard_types::Code::NumpadSubtract => BevyKeyCode::NumpadSubtract,
+        keyboard_types::Code::Escape => BevyKeyCode::Escape,
+        keyboard_types::Code::F1 => BevyKeyCode::F1,
+        keyboard_types::Code::F2 => BevyKeyCode::F2,
+        keyboard_types::Code::F3 => BevyKeyCode::F3,
+        keyboard_types::Code::F4 => BevyKeyCode::F4,
+        keyboard_types::Code::F5 => BevyKeyCode::F5,
+        keyboard_types::Code::F6 => BevyKeyCode::F6,
+        keyboard_types::Code::F7 => BevyKeyCode::F7,
+        keyboard_types::Code::F8 => BevyKeyCode::F8,
+        keyboard_types::Code::F9 => BevyKeyCode::F9,
+        keyboard_types::Code::F10 => BevyKeyCode::F10,
+        keyboard_types::Code::F11 => BevyKeyCode::F11,
+        keyboard_types::Code::F12 => BevyKeyCode::F12,
+        keyboard_types::Code::Fn => BevyKeyCode::Fn,
+        keyboard_types::Code::FnLock => BevyKeyCode::FnLock,
+        keyboard_types::Code::PrintScreen => BevyKeyCode::PrintScreen,
+        keyboard_types::Code::ScrollLock => BevyKeyCode::ScrollLock,
+        keyboard_types::Code::Pause => BevyKeyCode::Pause,
+        keyboard_types::Code::BrowserBack => BevyKeyCode::BrowserBack,
+        keyboard_types::Code::BrowserFavorites => BevyKeyCode::BrowserFavorites,
+        keyboard_types::Code::BrowserForward => BevyKeyCode::BrowserForward,
+        keyboard_types::Code::BrowserHome => BevyKeyCode::BrowserHome,
+        keyboard_types::Code::BrowserRefresh => BevyKeyCode::BrowserRefresh,
+        keyboard_types::Code::BrowserSearch => BevyKeyCode::BrowserSearch,
+        keyboard_types::Code::BrowserStop => BevyKeyCode::BrowserStop,
+        keyboard_types::Code::Eject => BevyKeyCode::Eject,
+        keyboard_types::Code::LaunchApp1 => BevyKeyCode::LaunchApp1,
+        keyboard_types::Code::LaunchApp2 => BevyKeyCode::LaunchApp2,
+        keyboard_types::Code::LaunchMail => BevyKeyCode::LaunchMail,
+        keyboard_types::Code::MediaPlayPause => BevyKeyCode::MediaPlayPause,
+        keyboard_types::Code::MediaSelect => BevyKeyCode::MediaSelect,
+        keyboard_types::Code::MediaStop => BevyKeyCode::MediaStop,
+        keyboard_types::Code::MediaTrackNext => BevyKeyCode::MediaTrackNext,
+        keyboard_types::Code::MediaTrackPrevious => BevyKeyCode::MediaTrackPrevious,
+        keyboard_types::Code::Power => BevyKeyCode::Power,
+        keyboard_types::Code::Sleep => BevyKeyCode::Sleep,
+        keyboard_types::Code::AudioVolumeDown => BevyKeyCode::AudioVolumeDown,
+        keyboard_types::Code::AudioVolumeMute => BevyKeyCode::AudioVolumeMute,
+        keyboard_types::Code::AudioVolumeUp => BevyKeyCode::AudioVolumeUp,
+        keyboard_types::Code::WakeUp => BevyKeyCode::WakeUp,
+        keyboard_types::Code::Hyper => BevyKeyCode::Hyper,
+        keyboard_types::Code::Super => BevyKeyCode::Meta,
+        keyboard_types::Code::Turbo => BevyKeyCode::Turbo,
+        keyboard_types::Code::Abort => BevyKeyCode::Abort,
+        keyboard_types::Code::Resume => BevyKeyCode::Resume,
+        keyboard_types::Code::Suspend => BevyKeyCode::Suspend,
+        keyboard_types::Code::Again => BevyKeyCode::Again,
+        keyboard_types::Code::Copy => BevyKeyCode::Copy,
+        keyboard_types::Code::Cut => BevyKeyCode::Cut,
+        keyboard_types::Code::Find => BevyKeyCode::Find,
+        keyboard_types::Code::Open => BevyKeyCode::Open,
+        keyboard_types::Code::Paste => BevyKeyCode::Paste,
+        keyboard_types::Code::Props => BevyKeyCode::Props,
+        keyboard_types::Code::Select => BevyKeyCode::Select,
+        keyboard_types::Code::Undo => BevyKeyCode::Undo,
+        keyboard_types::Code::Hiragana => BevyKeyCode::Hiragana,
+        keyboard_types::Code::Katakana => BevyKeyCode::Katakana,
+        keyboard_types::Code::Unidentified => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::F13 => BevyKeyCode::F13,
+        keyboard_types::Code::F14 => BevyKeyCode::F14,
+        keyboard_types::Code::F15 => BevyKeyCode::F15,
+        keyboard_types::Code::F16 => BevyKeyCode::F16,
+        keyboard_types::Code::F17 => BevyKeyCode::F17,
+        keyboard_types::Code::F18 => BevyKeyCode::F18,
+        keyboard_types::Code::F19 => BevyKeyCode::F19,
+        keyboard_types::Code::F20 => BevyKeyCode::F20,
+        keyboard_types::Code::F21 => BevyKeyCode::F21,
+        keyboard_types::Code::F22 => BevyKeyCode::F22,
+        keyboard_types::Code::F23 => BevyKeyCode::F23,
+        keyboard_types::Code::F24 => BevyKeyCode::F24,
+        keyboard_types::Code::BrightnessDown => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::BrightnessUp => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::DisplayToggleIntExt => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::KeyboardLayoutSelect => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::LaunchAssistant => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::LaunchControlPanel => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::LaunchScreenSaver => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MailForward => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MailReply => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MailSend => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MediaFastForward => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MediaPause => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MediaPlay => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MediaRecord => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MediaRewind => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::MicrophoneMuteToggle => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::PrivacyScreenToggle => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::SelectTask => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::ShowAllWindows => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+        keyboard_types::Code::ZoomToggle => BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
     }
 }
 
@@ -598,6 +512,116 @@ pub fn key_to_bevy_key(key: keyboard_types::Key) -> Key {
         keyboard_types::Key::F22 => Key::F22,
         keyboard_types::Key::F23 => Key::F23,
         keyboard_types::Key::F24 => Key::F24,
-        _ => todo!(),
+        _ => Key::Unidentified(bevy::input::keyboard::NativeKey::Unidentified),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    /// Every named `keyboard_types::Key` variant, as spelled by the UI Events spec.
+    const NAMED_KEYS: &[&str] = &[
+        "Unidentified", "Alt", "AltGraph", "CapsLock", "Control", "Fn", "FnLock", "Meta",
+        "NumLock", "ScrollLock", "Shift", "Symbol", "SymbolLock", "Hyper", "Super", "Enter",
+        "Tab", "ArrowDown", "ArrowLeft", "ArrowRight", "ArrowUp", "End", "Home", "PageDown",
+        "PageUp", "Backspace", "Clear", "Copy", "CrSel", "Cut", "Delete", "EraseEof", "ExSel",
+        "Insert", "Paste", "Redo", "Undo", "Accept", "Again", "Attn", "Cancel", "ContextMenu",
+        "Escape", "Execute", "Find", "Help", "Pause", "Play", "Props", "Select", "ZoomIn",
+        "ZoomOut", "BrightnessDown", "BrightnessUp", "Eject", "LogOff", "Power", "PowerOff",
+        "PrintScreen", "Hibernate", "Standby", "WakeUp", "AllCandidates", "Alphanumeric",
+        "CodeInput", "Compose", "Convert", "Dead", "FinalMode", "GroupFirst", "GroupLast",
+        "GroupNext", "GroupPrevious", "ModeChange", "NextCandidate", "NonConvert",
+        "PreviousCandidate", "Process", "SingleCandidate", "HangulMode", "HanjaMode",
+        "JunjaMode", "Eisu", "Hankaku", "Hiragana", "HiraganaKatakana", "KanaMode", "KanjiMode",
+        "Katakana", "Romaji", "Zenkaku", "ZenkakuHankaku", "F1", "F2", "F3", "F4", "F5", "F6",
+        "F7", "F8", "F9", "F10", "F11", "F12", "Soft1", "Soft2", "Soft3", "Soft4",
+        "ChannelDown", "ChannelUp", "Close", "MailForward", "MailReply", "MailSend",
+        "MediaClose", "MediaFastForward", "MediaPause", "MediaPlay", "MediaPlayPause",
+        "MediaRecord", "MediaRewind", "MediaStop", "MediaTrackNext", "MediaTrackPrevious",
+        "New", "Open", "Print", "Save", "SpellCheck", "Key11", "Key12", "AudioBalanceLeft",
+        "AudioBalanceRight", "AudioBassBoostDown", "AudioBassBoostToggle", "AudioBassBoostUp",
+        "AudioFaderFront", "AudioFaderRear", "AudioSurroundModeNext", "AudioTrebleDown",
+        "AudioTrebleUp", "AudioVolumeDown", "AudioVolumeUp", "AudioVolumeMute",
+        "MicrophoneToggle", "MicrophoneVolumeDown", "MicrophoneVolumeUp",
+        "MicrophoneVolumeMute", "SpeechCorrectionList", "SpeechInputToggle",
+        "LaunchApplication1", "LaunchApplication2", "LaunchCalendar", "LaunchContacts",
+        "LaunchMail", "LaunchMediaPlayer", "LaunchMusicPlayer", "LaunchPhone",
+        "LaunchScreenSaver", "LaunchSpreadsheet", "LaunchWebBrowser", "LaunchWebCam",
+        "LaunchWordProcessor", "BrowserBack", "BrowserFavorites", "BrowserForward",
+        "BrowserHome", "BrowserRefresh", "BrowserSearch", "BrowserStop", "AppSwitch", "Call",
+        "Camera", "CameraFocus", "EndCall", "GoBack", "GoHome", "HeadsetHook",
+        "LastNumberRedial", "Notification", "MannerMode", "VoiceDial", "TV", "TV3DMode",
+        "TVAntennaCable", "TVAudioDescription", "TVAudioDescriptionMixDown",
+        "TVAudioDescriptionMixUp", "TVContentsMenu", "TVDataService", "TVInput",
+        "TVInputComponent1", "TVInputComponent2", "TVInputComposite1", "TVInputComposite2",
+        "TVInputHDMI1", "TVInputHDMI2", "TVInputHDMI3", "TVInputHDMI4", "TVInputVGA1",
+        "TVMediaContext", "TVNetwork", "TVNumberEntry", "TVPower", "TVRadioService",
+        "TVSatellite", "TVSatelliteBS", "TVSatelliteCS", "TVSatelliteToggle",
+        "TVTerrestrialAnalog", "TVTerrestrialDigital", "TVTimer", "AVRInput", "AVRPower",
+        "ColorF0Red", "ColorF1Green", "ColorF2Yellow", "ColorF3Blue", "ColorF4Grey",
+        "ColorF5Brown", "ClosedCaptionToggle", "Dimmer", "DisplaySwap", "DVR", "Exit",
+        "FavoriteClear0", "FavoriteClear1", "FavoriteClear2", "FavoriteClear3",
+        "FavoriteRecall0", "FavoriteRecall1", "FavoriteRecall2", "FavoriteRecall3",
+        "FavoriteStore0", "FavoriteStore1", "FavoriteStore2", "FavoriteStore3", "Guide",
+        "GuideNextDay", "GuidePreviousDay", "Info", "InstantReplay", "Link", "ListProgram",
+        "LiveContent", "Lock", "MediaApps", "MediaAudioTrack", "MediaLast", "MediaSkipBackward",
+        "MediaSkipForward", "MediaStepBackward", "MediaStepForward", "MediaTopMenu",
+        "NavigateIn", "NavigateNext", "NavigateOut", "NavigatePrevious", "NextFavoriteChannel",
+        "NextUserProfile", "OnDemand", "Pairing", "PinPDown", "PinPMove", "PinPToggle",
+        "PinPUp", "PlaySpeedDown", "PlaySpeedReset", "PlaySpeedUp", "RandomToggle",
+        "RcLowBattery", "RecordSpeedNext", "RfBypass", "ScanChannelsToggle", "ScreenModeNext",
+        "Settings", "SplitScreenToggle", "STBInput", "STBPower", "Subtitle", "Teletext",
+        "VideoModeNext", "Wink", "ZoomToggle", "F13", "F14", "F15", "F16", "F17", "F18", "F19",
+        "F20", "F21", "F22", "F23", "F24",
+    ];
+
+    #[test]
+    fn every_named_key_maps_to_bevy_key_of_same_name() {
+        for name in NAMED_KEYS {
+            let key = keyboard_types::Key::from_str(name)
+                .unwrap_or_else(|_| panic!("{name} is not a keyboard_types::Key"));
+            let bevy_key = key_to_bevy_key(key);
+
+            match bevy_key {
+                Key::Dead(None) => assert_eq!(*name, "Dead"),
+                Key::Unidentified(_) => assert_eq!(*name, "Unidentified"),
+                other => assert_eq!(format!("{other:?}"), *name),
+            }
+        }
+    }
+
+    #[test]
+    fn codes_map_to_physical_key_codes() {
+        let table = [
+            (keyboard_types::Code::KeyQ, BevyKeyCode::KeyQ),
+            (keyboard_types::Code::KeyA, BevyKeyCode::KeyA),
+            (keyboard_types::Code::ShiftLeft, BevyKeyCode::ShiftLeft),
+            (keyboard_types::Code::ShiftRight, BevyKeyCode::ShiftRight),
+            (keyboard_types::Code::AltLeft, BevyKeyCode::AltLeft),
+            (keyboard_types::Code::AltRight, BevyKeyCode::AltRight),
+            (keyboard_types::Code::MetaLeft, BevyKeyCode::SuperLeft),
+            (keyboard_types::Code::MetaRight, BevyKeyCode::SuperRight),
+            (keyboard_types::Code::Numpad5, BevyKeyCode::Numpad5),
+            (keyboard_types::Code::F12, BevyKeyCode::F12),
+            (
+                keyboard_types::Code::Unidentified,
+                BevyKeyCode::Unidentified(NativeKeyCode::Unidentified),
+            ),
+        ];
+
+        for (code, expected) in table {
+            assert_eq!(code_to_keycode(code), expected, "{code:?}");
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn characters_are_preserved() {
+        for text in ["a", "Z", "1", " ", "é", "ß"] {
+            let key = keyboard_types::Key::Character(text.to_string());
+            assert_eq!(key_to_bevy_key(key), Key::Character(text.into()));
+        }
+    }
+}
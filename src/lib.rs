@@ -1,24 +1,48 @@
 mod window;
+mod app_builder;
+mod batching;
+mod changed_window;
 mod conversions;
+mod crash;
+mod cursor_icon;
+mod cursor_warp;
+mod error;
+mod drag_drop;
+mod event_status;
+mod frame_pacing;
+mod headless;
 mod keyboard;
+mod ime;
+mod modifiers;
+mod native_window;
 mod parent_window;
+mod resize;
+mod update_mode;
 mod default_plugins;
 
 use std::sync::{Arc, Mutex};
 
-use bevy::ecs::system::SystemState;
-use bevy::log::info;
-use bevy::prelude::{Commands, Entity, EventWriter, FromWorld, Query, With};
-
-use bevy::app::{App, PluginsState};
-use bevy::window::{PrimaryWindow, RawHandleWrapper, RawHandleWrapperHolder, Window, WindowCreated, WindowWrapper};
-
-use parent_window::RawWindow;
+use parent_window::ParentWindowHandle;
 use rwh_05::HasRawWindowHandle;
 use window::BevyWindow;
 
+pub use app_builder::BaseviewAppBuilder;
+pub use batching::EventBatching;
+pub use crash::CrashHandler;
+pub use cursor_icon::BaseviewCursorIcon;
 pub use default_plugins::DefaultBaseviewPlugins;
-
+pub use drag_drop::DropTarget;
+pub use error::BaseviewError;
+pub use event_status::{BaseviewAppExt, EventStatusHook, KeyboardCapturePolicy};
+pub use frame_pacing::{FrameDiagnostics, FramePacing};
+pub use headless::{HeadlessRenderTarget, HeadlessWindow};
+pub use modifiers::BaseviewModifiers;
+pub use resize::ResizeNegotiation;
+pub use update_mode::{BaseviewSettings, RedrawSignal, UpdateMode};
+
+/// Opens the editor inside `parent_window`, panicking if the app cannot be set up.
+///
+/// See [`try_open_parented`] for a version that reports failures instead.
 pub fn open_parented<P, B>(
     parent_window: P,
     window_open_options: baseview::WindowOpenOptions,
@@ -26,57 +50,106 @@ pub fn open_parented<P, B>(
 ) -> baseview::WindowHandle
     where
     P: HasRawWindowHandle,
-    B: FnOnce(&mut App) -> &mut App + Send + Sync + 'static
+    B: Into<BaseviewAppBuilder>
 {
-    baseview::Window::open_parented(
-        &parent_window, 
-        window_open_options, 
-        |window| {
-            let mut app = App::new();
-            app_builder(&mut app);
-
-            while app.plugins_state() == PluginsState::Adding {
-                bevy::tasks::tick_global_task_pools_on_main_thread();
-            }
-            app.finish();
-            app.cleanup();
-        
-            app.update();
-
-            let mut create_window_system_state: SystemState<(
-                Commands,
-                Query<(Entity, &mut Window), With<PrimaryWindow>>,
-                EventWriter<WindowCreated>,
-            )> = SystemState::from_world(app.world_mut());
-
-            let (
-                mut commands,
-                mut windows,
-                mut event_writer,
-            ) = create_window_system_state.get_mut(app.world_mut());
-
-            let (entity, window_comp) = windows.single_mut();
-
-            info!(
-                "Creating new window {:?} ({:?})",
-                window_comp.title.as_str(),
-                entity
-            );
-
-            let window_wrapper = WindowWrapper::new(RawWindow::new(window));
-
-            if let Ok(handle_wrapper) = RawHandleWrapper::new(&window_wrapper) {
-                commands
-                    .entity(entity)
-                    .insert(handle_wrapper.clone())
-                    .insert(RawHandleWrapperHolder(Arc::new(Mutex::new(Some(handle_wrapper.clone())))));
+    match try_open_parented(parent_window, window_open_options, app_builder) {
+        Ok(window_handle) => window_handle,
+        Err(err) => panic!("Failed to open baseview window: {}", err),
+    }
+}
+
+/// Opens the editor inside `parent_window`.
+///
+/// If the app cannot be set up, the baseview window is closed again and the reason is
+/// returned, so a plugin can report it to the host instead of taking the host down.
+pub fn try_open_parented<P, B>(
+    parent_window: P,
+    window_open_options: baseview::WindowOpenOptions,
+    app_builder: B
+) -> Result<baseview::WindowHandle, BaseviewError>
+    where
+    P: HasRawWindowHandle,
+    B: Into<BaseviewAppBuilder>
+{
+    let error = Arc::new(Mutex::new(None));
+
+    let mut window_handle = baseview::Window::open_parented(
+        &parent_window,
+        window_open_options,
+        build_handler(app_builder.into(), error.clone(), false)
+    );
+
+    // Every baseview backend runs the build closure before `open_parented` returns.
+    let error = error.lock().unwrap().take();
+    match error {
+        Some(err) => {
+            window_handle.close();
+            Err(err)
+        }
+        None => Ok(window_handle),
+    }
+}
+
+/// Opens the editor inside a parent given as a raw-window-handle 0.6 window, as handed out
+/// by hosts and windowing crates built on newer raw-window-handle versions.
+pub fn try_open_parented_rwh06<P, B>(
+    parent_window: &P,
+    window_open_options: baseview::WindowOpenOptions,
+    app_builder: B
+) -> Result<baseview::WindowHandle, BaseviewError>
+    where
+    P: rwh_06::HasWindowHandle + ?Sized,
+    B: Into<BaseviewAppBuilder>
+{
+    let parent_window = ParentWindowHandle::try_from(parent_window.window_handle()?.as_raw())?;
 
-                event_writer.send(WindowCreated { window: entity });
+    try_open_parented(parent_window, window_open_options, app_builder)
+}
 
-                create_window_system_state.apply(app.world_mut());
+/// Opens the editor in its own top-level window and runs it until the window closes.
+///
+/// Useful for standalone builds and prototypes, which then need no other windowing crate.
+pub fn open_blocking<B>(
+    window_open_options: baseview::WindowOpenOptions,
+    app_builder: B
+) -> Result<(), BaseviewError>
+    where
+    B: Into<BaseviewAppBuilder>
+{
+    let error = Arc::new(Mutex::new(None));
+
+    baseview::Window::open_blocking(
+        window_open_options,
+        build_handler(app_builder.into(), error.clone(), true)
+    );
+
+    let error = error.lock().unwrap().take();
+    match error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// The baseview build closure shared by every entry point.
+///
+/// A setup failure is stored in `error` and leaves a closed [`BevyWindow`] behind; with
+/// `close_on_error` the baseview window is closed from inside, which is what ends a
+/// blocking window.
+fn build_handler(
+    app_builder: BaseviewAppBuilder,
+    error: Arc<Mutex<Option<BaseviewError>>>,
+    close_on_error: bool
+) -> impl FnOnce(&mut baseview::Window) -> BevyWindow + Send + 'static
+{
+    move |window| match app_builder.build(window) {
+        Ok(app) => BevyWindow::new(app),
+        Err(err) => {
+            log::error!("Failed to open baseview window: {}", err);
+            *error.lock().unwrap() = Some(err);
+            if close_on_error {
+                window.close();
             }
-
-            BevyWindow::new(app)
+            BevyWindow::closed()
         }
-    )
-}
\ No newline at end of file
+    }
+}
@@ -0,0 +1,203 @@
+use bevy::ecs::system::Resource;
+use bevy::input::keyboard::{Key, KeyCode, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::Entity;
+use keyboard_types::Modifiers;
+
+/// The modifier state reported by baseview with the most recent event.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BaseviewModifiers(pub Modifiers);
+
+const MODIFIER_KEYS: [(Modifiers, KeyCode, KeyCode); 4] = [
+    (Modifiers::SHIFT, KeyCode::ShiftLeft, KeyCode::ShiftRight),
+    (Modifiers::CONTROL, KeyCode::ControlLeft, KeyCode::ControlRight),
+    (Modifiers::ALT, KeyCode::AltLeft, KeyCode::AltRight),
+    (Modifiers::META, KeyCode::SuperLeft, KeyCode::SuperRight),
+];
+
+const MODIFIER_FLAGS: Modifiers = Modifiers::SHIFT
+    .union(Modifiers::CONTROL)
+    .union(Modifiers::ALT)
+    .union(Modifiers::META);
+
+fn modifier_flag(key_code: KeyCode) -> Option<Modifiers> {
+    MODIFIER_KEYS
+        .iter()
+        .find(|(_, left, right)| key_code == *left || key_code == *right)
+        .map(|(flag, _, _)| *flag)
+}
+
+/// Whether `key_code` is one of the modifier keys tracked by [`ModifierKeys`].
+pub fn is_modifier_key(key_code: KeyCode) -> bool {
+    modifier_flag(key_code).is_some()
+}
+
+fn logical_key(flag: Modifiers) -> Key {
+    match flag {
+        Modifiers::SHIFT => Key::Shift,
+        Modifiers::CONTROL => Key::Control,
+        Modifiers::ALT => Key::Alt,
+        _ => Key::Super,
+    }
+}
+
+/// The modifier state carried by a baseview event, if any.
+///
+/// Keyboard events for modifier keys are skipped: platforms disagree on whether their
+/// modifiers are sampled before or after the key itself, and the key event is authoritative.
+pub fn event_modifiers(event: &baseview::Event) -> Option<Modifiers> {
+    match event {
+        baseview::Event::Mouse(e) => match e {
+            baseview::MouseEvent::CursorMoved { modifiers, .. }
+            | baseview::MouseEvent::ButtonPressed { modifiers, .. }
+            | baseview::MouseEvent::ButtonReleased { modifiers, .. }
+            | baseview::MouseEvent::WheelScrolled { modifiers, .. }
+            | baseview::MouseEvent::DragEntered { modifiers, .. }
+            | baseview::MouseEvent::DragMoved { modifiers, .. }
+            | baseview::MouseEvent::DragDropped { modifiers, .. } => Some(*modifiers),
+            _ => None,
+        },
+        baseview::Event::Keyboard(e) => {
+            if is_modifier_key(crate::keyboard::code_to_keycode(e.code)) {
+                None
+            } else {
+                Some(e.modifiers)
+            }
+        }
+        baseview::Event::Window(_) => None,
+    }
+}
+
+/// Mirrors the modifier keys the backend has reported to Bevy as held.
+///
+/// Used to synthesize [`KeyboardInput`] events when the host swallowed a modifier
+/// press or release, so `ButtonInput<KeyCode>` never keeps a modifier stuck.
+#[derive(Debug, Default)]
+pub struct ModifierKeys {
+    held: Vec<KeyCode>,
+}
+
+impl ModifierKeys {
+    /// Records a key event that is about to be sent to Bevy.
+    pub fn observe(&mut self, key_code: KeyCode, state: ButtonState) {
+        if !is_modifier_key(key_code) {
+            return;
+        }
+
+        match state {
+            ButtonState::Pressed => {
+                if !self.held.contains(&key_code) {
+                    self.held.push(key_code);
+                }
+            }
+            ButtonState::Released => self.held.retain(|held| *held != key_code),
+        }
+    }
+
+    /// The modifier flags implied by the held modifier keys.
+    pub fn modifiers(&self) -> Modifiers {
+        self.held
+            .iter()
+            .filter_map(|key_code| modifier_flag(*key_code))
+            .fold(Modifiers::empty(), |acc, flag| acc | flag)
+    }
+
+    /// The modifier keys currently held.
+    pub fn held(&self) -> impl Iterator<Item = KeyCode> + '_ {
+        self.held.iter().copied()
+    }
+
+    /// `reported` with its Shift/Control/Alt/Meta flags replaced by the held modifier keys.
+    pub fn merge(&self, reported: Modifiers) -> Modifiers {
+        (reported - MODIFIER_FLAGS) | self.modifiers()
+    }
+
+    /// Returns the events needed to bring the held modifier keys in line with `reported`.
+    pub fn sync(&mut self, window: Entity, reported: Modifiers) -> Vec<KeyboardInput> {
+        let mut events = Vec::new();
+
+        for (flag, left, right) in MODIFIER_KEYS {
+            let held: Vec<KeyCode> = self
+                .held
+                .iter()
+                .copied()
+                .filter(|key_code| *key_code == left || *key_code == right)
+                .collect();
+
+            if reported.contains(flag) && held.is_empty() {
+                events.push(synthesized_input(window, left, flag, ButtonState::Pressed));
+            } else if !reported.contains(flag) {
+                for key_code in held {
+                    events.push(synthesized_input(window, key_code, flag, ButtonState::Released));
+                }
+            }
+        }
+
+        for event in &events {
+            self.observe(event.key_code, event.state);
+        }
+
+        events
+    }
+
+    /// Forgets every held modifier key, e.g. when the window loses focus.
+    pub fn clear(&mut self) {
+        self.held.clear();
+    }
+}
+
+fn synthesized_input(
+    window: Entity,
+    key_code: KeyCode,
+    flag: Modifiers,
+    state: ButtonState,
+) -> KeyboardInput {
+    KeyboardInput {
+        key_code,
+        logical_key: logical_key(flag),
+        state,
+        repeat: false,
+        window,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swallowed_release_is_synthesized() {
+        let window = Entity::from_raw(0);
+        let mut keys = ModifierKeys::default();
+        keys.observe(KeyCode::ShiftLeft, ButtonState::Pressed);
+
+        let events = keys.sync(window, Modifiers::empty());
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].key_code, KeyCode::ShiftLeft);
+        assert_eq!(events[0].state, ButtonState::Released);
+        assert_eq!(keys.modifiers(), Modifiers::empty());
+    }
+
+    #[test]
+    fn swallowed_press_is_synthesized_once() {
+        let window = Entity::from_raw(0);
+        let mut keys = ModifierKeys::default();
+
+        let events = keys.sync(window, Modifiers::CONTROL | Modifiers::ALT);
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|e| e.state == ButtonState::Pressed));
+        assert_eq!(keys.modifiers(), Modifiers::CONTROL | Modifiers::ALT);
+
+        assert!(keys.sync(window, Modifiers::CONTROL | Modifiers::ALT).is_empty());
+    }
+
+    #[test]
+    fn right_hand_modifier_satisfies_flag() {
+        let window = Entity::from_raw(0);
+        let mut keys = ModifierKeys::default();
+        keys.observe(KeyCode::ShiftRight, ButtonState::Pressed);
+
+        assert!(keys.sync(window, Modifiers::SHIFT).is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::math::DVec2;
+
+use crate::cursor_warp;
+
+/// What `BevyWindow` needs from the window it drives each frame.
+///
+/// Implemented by `baseview::Window`, and by the offscreen stand-in of
+/// [`HeadlessWindow`](crate::HeadlessWindow) so it runs the same frame path.
+pub trait NativeWindow {
+    fn set_mouse_cursor(&mut self, cursor: baseview::MouseCursor);
+
+    /// Resizes the window to `size`, in logical pixels.
+    fn resize(&mut self, size: baseview::Size);
+
+    /// Asks the host to close the window, which then delivers `WindowEvent::WillClose`.
+    fn close(&mut self);
+
+    /// Moves the pointer to `position`, in logical window coordinates, returning whether it
+    /// moved. See [`cursor_warp::warp_cursor`].
+    fn warp_cursor(&mut self, position: DVec2, scale_factor: f64) -> bool;
+}
+
+impl NativeWindow for baseview::Window<'_> {
+    fn set_mouse_cursor(&mut self, cursor: baseview::MouseCursor) {
+        baseview::Window::set_mouse_cursor(self, cursor);
+    }
+
+    fn resize(&mut self, size: baseview::Size) {
+        baseview::Window::resize(self, size);
+    }
+
+    fn close(&mut self) {
+        baseview::Window::close(self);
+    }
+
+    fn warp_cursor(&mut self, position: DVec2, scale_factor: f64) -> bool {
+        cursor_warp::warp_cursor(self, position, scale_factor)
+    }
+}
+
+/// Records what a `BevyWindow` asked of its window, for tests.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct RecordingWindow {
+    pub cursor: Option<baseview::MouseCursor>,
+    pub sizes: Vec<baseview::Size>,
+    pub close_requested: bool,
+}
+
+#[cfg(test)]
+impl NativeWindow for RecordingWindow {
+    fn set_mouse_cursor(&mut self, cursor: baseview::MouseCursor) {
+        self.cursor = Some(cursor);
+    }
+
+    fn resize(&mut self, size: baseview::Size) {
+        self.sizes.push(size);
+    }
+
+    fn close(&mut self) {
+        self.close_requested = true;
+    }
+
+    fn warp_cursor(&mut self, _position: DVec2, _scale_factor: f64) -> bool {
+        false
+    }
+}
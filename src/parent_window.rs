@@ -1,7 +1,9 @@
-use std::{num::NonZero, ptr::NonNull};
+use std::{ffi::c_void, num::NonZero, ptr::NonNull};
 
 use rwh_05::{HasRawDisplayHandle, HasRawWindowHandle};
 
+use crate::error::BaseviewError;
+
 #[derive(Clone, Debug)]
 pub struct RawWindow{
     window_handle: rwh_06::RawWindowHandle,
@@ -28,11 +30,11 @@ impl rwh_06::HasDisplayHandle for RawWindow {
 }
 
 impl RawWindow {
-    pub fn new(window: &baseview::Window) -> Self{
-        Self {
-            window_handle: OldRawWindowHandle(window.raw_window_handle()).into(),
-            display_handle: OldRawDisplayHandle(window.raw_display_handle()).into()
-        }
+    pub fn new(window: &baseview::Window) -> Result<Self, BaseviewError> {
+        Ok(Self {
+            window_handle: OldRawWindowHandle(window.raw_window_handle()).try_into()?,
+            display_handle: OldRawDisplayHandle(window.raw_display_handle()).try_into()?
+        })
     }
 }
 
@@ -51,47 +53,48 @@ impl RawWindow {
 pub struct OldRawWindowHandle(pub rwh_05::RawWindowHandle);
 pub struct OldRawDisplayHandle(pub rwh_05::RawDisplayHandle);
 
-impl Into<rwh_06::RawWindowHandle> for OldRawWindowHandle {
-    fn into(self) -> rwh_06::RawWindowHandle {
-        match self.0 {
+/// Rejects null pointers handed to us by baseview or the host, naming the offending field.
+fn non_null(ptr: *mut c_void, field: &'static str) -> Result<NonNull<c_void>, BaseviewError> {
+    NonNull::new(ptr).ok_or(BaseviewError::NullHandle(field))
+}
+
+impl TryFrom<OldRawWindowHandle> for rwh_06::RawWindowHandle {
+    type Error = BaseviewError;
+
+    fn try_from(handle: OldRawWindowHandle) -> Result<Self, Self::Error> {
+        Ok(match handle.0 {
             rwh_05::RawWindowHandle::UiKit(handle) => {
-                rwh_06::RawWindowHandle::UiKit(
-                    rwh_06::UiKitWindowHandle::new(unsafe { 
-                        NonNull::new_unchecked(handle.ui_view) 
-                    })
-                )
+                let mut ui_kit = rwh_06::UiKitWindowHandle::new(non_null(handle.ui_view, "ui_view")?);
+                ui_kit.ui_view_controller = NonNull::new(handle.ui_view_controller);
+                rwh_06::RawWindowHandle::UiKit(ui_kit)
             },
             rwh_05::RawWindowHandle::AppKit(handle) => {
                 rwh_06::RawWindowHandle::AppKit(
-                    rwh_06::AppKitWindowHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.ns_view)
-                    })
+                    rwh_06::AppKitWindowHandle::new(non_null(handle.ns_view, "ns_view")?)
                 )
             },
             rwh_05::RawWindowHandle::Orbital(handle) => {
                 rwh_06::RawWindowHandle::Orbital(
-                    rwh_06::OrbitalWindowHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.window)
-                    })
+                    rwh_06::OrbitalWindowHandle::new(non_null(handle.window, "window")?)
                 )
             },
             rwh_05::RawWindowHandle::Xlib(handle) => {
-                rwh_06::RawWindowHandle::Xlib(
-                    rwh_06::XlibWindowHandle::new(handle.window)
-                )
+                if handle.window == 0 {
+                    return Err(BaseviewError::NullHandle("window"));
+                }
+                let mut xlib = rwh_06::XlibWindowHandle::new(handle.window);
+                xlib.visual_id = handle.visual_id;
+                rwh_06::RawWindowHandle::Xlib(xlib)
             },
             rwh_05::RawWindowHandle::Xcb(handle) => {
-                rwh_06::RawWindowHandle::Xcb(
-                    rwh_06::XcbWindowHandle::new(unsafe {
-                        NonZero::new_unchecked(handle.window)
-                    })
-                )
+                let window = NonZero::new(handle.window).ok_or(BaseviewError::NullHandle("window"))?;
+                let mut xcb = rwh_06::XcbWindowHandle::new(window);
+                xcb.visual_id = NonZero::new(handle.visual_id);
+                rwh_06::RawWindowHandle::Xcb(xcb)
             },
             rwh_05::RawWindowHandle::Wayland(handle) => {
                 rwh_06::RawWindowHandle::Wayland(
-                    rwh_06::WaylandWindowHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.surface)
-                    })
+                    rwh_06::WaylandWindowHandle::new(non_null(handle.surface, "surface")?)
                 )
             },
             rwh_05::RawWindowHandle::Drm(handle) => {
@@ -101,23 +104,18 @@ impl Into<rwh_06::RawWindowHandle> for OldRawWindowHandle {
             },
             rwh_05::RawWindowHandle::Gbm(handle) => {
                 rwh_06::RawWindowHandle::Gbm(
-                    rwh_06::GbmWindowHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.gbm_surface)
-                    })
+                    rwh_06::GbmWindowHandle::new(non_null(handle.gbm_surface, "gbm_surface")?)
                 )
             },
             rwh_05::RawWindowHandle::Win32(handle) => {
-                rwh_06::RawWindowHandle::Win32(
-                    rwh_06::Win32WindowHandle::new(unsafe {
-                        NonZero::new_unchecked(handle.hwnd as isize)
-                    })
-                )
+                let hwnd = NonZero::new(handle.hwnd as isize).ok_or(BaseviewError::NullHandle("hwnd"))?;
+                let mut win32 = rwh_06::Win32WindowHandle::new(hwnd);
+                win32.hinstance = NonZero::new(handle.hinstance as isize);
+                rwh_06::RawWindowHandle::Win32(win32)
             },
             rwh_05::RawWindowHandle::WinRt(handle) => {
                 rwh_06::RawWindowHandle::WinRt(
-                    rwh_06::WinRtWindowHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.core_window)
-                    })
+                    rwh_06::WinRtWindowHandle::new(non_null(handle.core_window, "core_window")?)
                 )
             },
             rwh_05::RawWindowHandle::Web(handle) => {
@@ -127,26 +125,24 @@ impl Into<rwh_06::RawWindowHandle> for OldRawWindowHandle {
             },
             rwh_05::RawWindowHandle::AndroidNdk(handle) => {
                 rwh_06::RawWindowHandle::AndroidNdk(
-                    rwh_06::AndroidNdkWindowHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.a_native_window)
-                    })
+                    rwh_06::AndroidNdkWindowHandle::new(non_null(handle.a_native_window, "a_native_window")?)
                 )
             },
             rwh_05::RawWindowHandle::Haiku(handle) => {
-                rwh_06::RawWindowHandle::Haiku(
-                    rwh_06::HaikuWindowHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.b_window)
-                    })
-                )
+                let mut haiku = rwh_06::HaikuWindowHandle::new(non_null(handle.b_window, "b_window")?);
+                haiku.b_direct_window = NonNull::new(handle.b_direct_window);
+                rwh_06::RawWindowHandle::Haiku(haiku)
             },
-            _ => panic!("Raw window handle conversion not supported"),
-        }
+            handle => return Err(BaseviewError::UnsupportedHandle(format!("{:?}", handle))),
+        })
     }
 }
 
-impl Into<rwh_06::RawDisplayHandle> for OldRawDisplayHandle {
-    fn into(self) -> rwh_06::RawDisplayHandle {
-        match self.0 {
+impl TryFrom<OldRawDisplayHandle> for rwh_06::RawDisplayHandle {
+    type Error = BaseviewError;
+
+    fn try_from(handle: OldRawDisplayHandle) -> Result<Self, Self::Error> {
+        Ok(match handle.0 {
             rwh_05::RawDisplayHandle::UiKit(_) => {
                 rwh_06::RawDisplayHandle::UiKit(
                     rwh_06::UiKitDisplayHandle::new()
@@ -162,31 +158,20 @@ impl Into<rwh_06::RawDisplayHandle> for OldRawDisplayHandle {
                     rwh_06::OrbitalDisplayHandle::new()
                 )
             },
+            // A null Xlib display or XCB connection means "open the default one".
             rwh_05::RawDisplayHandle::Xlib(handle) => {
                 rwh_06::RawDisplayHandle::Xlib(
-                    rwh_06::XlibDisplayHandle::new(
-                        Some(unsafe {
-                            NonNull::new_unchecked(handle.display)
-                        }),
-                        handle.screen
-                    )
+                    rwh_06::XlibDisplayHandle::new(NonNull::new(handle.display), handle.screen)
                 )
             },
             rwh_05::RawDisplayHandle::Xcb(handle) => {
                 rwh_06::RawDisplayHandle::Xcb(
-                    rwh_06::XcbDisplayHandle::new(
-                        Some(unsafe {
-                            NonNull::new_unchecked(handle.connection)
-                        }),
-                        handle.screen
-                    )
+                    rwh_06::XcbDisplayHandle::new(NonNull::new(handle.connection), handle.screen)
                 )
             },
             rwh_05::RawDisplayHandle::Wayland(handle) => {
                 rwh_06::RawDisplayHandle::Wayland(
-                    rwh_06::WaylandDisplayHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.display)
-                    })
+                    rwh_06::WaylandDisplayHandle::new(non_null(handle.display, "display")?)
                 )
             },
             rwh_05::RawDisplayHandle::Drm(handle) => {
@@ -196,9 +181,7 @@ impl Into<rwh_06::RawDisplayHandle> for OldRawDisplayHandle {
             },
             rwh_05::RawDisplayHandle::Gbm(handle) => {
                 rwh_06::RawDisplayHandle::Gbm(
-                    rwh_06::GbmDisplayHandle::new(unsafe {
-                        NonNull::new_unchecked(handle.gbm_device)
-                    })
+                    rwh_06::GbmDisplayHandle::new(non_null(handle.gbm_device, "gbm_device")?)
                 )
             },
             rwh_05::RawDisplayHandle::Web(_) => {
@@ -219,7 +202,173 @@ impl Into<rwh_06::RawDisplayHandle> for OldRawDisplayHandle {
                     rwh_06::WindowsDisplayHandle::new()
                 )
             }
-            _ => panic!("Raw window handle conversion not supported"),
+            handle => return Err(BaseviewError::UnsupportedHandle(format!("{:?}", handle))),
+        })
+    }
+}
+
+/// A parent window handed to us as a raw-window-handle 0.6 handle, in the 0.5 form
+/// baseview expects.
+///
+/// Only the handle types baseview can parent to are accepted: Xlib, XCB, AppKit and Win32.
+#[derive(Clone, Copy, Debug)]
+pub struct ParentWindowHandle(rwh_05::RawWindowHandle);
+
+unsafe impl HasRawWindowHandle for ParentWindowHandle {
+    fn raw_window_handle(&self) -> rwh_05::RawWindowHandle {
+        self.0
+    }
+}
+
+impl TryFrom<rwh_06::RawWindowHandle> for ParentWindowHandle {
+    type Error = BaseviewError;
+
+    fn try_from(handle: rwh_06::RawWindowHandle) -> Result<Self, Self::Error> {
+        Ok(ParentWindowHandle(match handle {
+            rwh_06::RawWindowHandle::Xlib(handle) => {
+                let mut xlib = rwh_05::XlibWindowHandle::empty();
+                xlib.window = handle.window;
+                xlib.visual_id = handle.visual_id;
+                rwh_05::RawWindowHandle::Xlib(xlib)
+            },
+            rwh_06::RawWindowHandle::Xcb(handle) => {
+                let mut xcb = rwh_05::XcbWindowHandle::empty();
+                xcb.window = handle.window.get();
+                xcb.visual_id = handle.visual_id.map_or(0, NonZero::get);
+                rwh_05::RawWindowHandle::Xcb(xcb)
+            },
+            rwh_06::RawWindowHandle::AppKit(handle) => {
+                let mut app_kit = rwh_05::AppKitWindowHandle::empty();
+                app_kit.ns_view = handle.ns_view.as_ptr();
+                rwh_05::RawWindowHandle::AppKit(app_kit)
+            },
+            rwh_06::RawWindowHandle::Win32(handle) => {
+                let mut win32 = rwh_05::Win32WindowHandle::empty();
+                win32.hwnd = handle.hwnd.get() as *mut c_void;
+                win32.hinstance = handle.hinstance.map_or(std::ptr::null_mut(), |hinstance| hinstance.get() as *mut c_void);
+                rwh_05::RawWindowHandle::Win32(win32)
+            },
+            handle => return Err(BaseviewError::UnsupportedHandle(format!("{:?}", handle))),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_pointer(address: usize) -> *mut c_void {
+        address as *mut c_void
+    }
+
+    #[test]
+    fn x11_handles_are_converted() {
+        let mut window = rwh_05::XlibWindowHandle::empty();
+        window.window = 42;
+        window.visual_id = 7;
+        let mut expected = rwh_06::XlibWindowHandle::new(42);
+        expected.visual_id = 7;
+        assert_eq!(
+            rwh_06::RawWindowHandle::try_from(OldRawWindowHandle(window.into())).unwrap(),
+            rwh_06::RawWindowHandle::Xlib(expected)
+        );
+
+        let mut window = rwh_05::XcbWindowHandle::empty();
+        window.window = 42;
+        assert_eq!(
+            rwh_06::RawWindowHandle::try_from(OldRawWindowHandle(window.into())).unwrap(),
+            rwh_06::RawWindowHandle::Xcb(rwh_06::XcbWindowHandle::new(NonZero::new(42).unwrap()))
+        );
+    }
+
+    #[test]
+    fn x11_displays_may_have_no_connection() {
+        let mut display = rwh_05::XlibDisplayHandle::empty();
+        display.screen = 1;
+        assert_eq!(
+            rwh_06::RawDisplayHandle::try_from(OldRawDisplayHandle(display.into())).unwrap(),
+            rwh_06::RawDisplayHandle::Xlib(rwh_06::XlibDisplayHandle::new(None, 1))
+        );
+
+        let mut display = rwh_05::XcbDisplayHandle::empty();
+        display.connection = fake_pointer(0x1000);
+        assert_eq!(
+            rwh_06::RawDisplayHandle::try_from(OldRawDisplayHandle(display.into())).unwrap(),
+            rwh_06::RawDisplayHandle::Xcb(rwh_06::XcbDisplayHandle::new(
+                NonNull::new(fake_pointer(0x1000)),
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn null_handles_are_rejected() {
+        let window = rwh_05::XlibWindowHandle::empty();
+        assert!(matches!(
+            rwh_06::RawWindowHandle::try_from(OldRawWindowHandle(window.into())),
+            Err(BaseviewError::NullHandle("window"))
+        ));
+
+        let window = rwh_05::Win32WindowHandle::empty();
+        assert!(matches!(
+            rwh_06::RawWindowHandle::try_from(OldRawWindowHandle(window.into())),
+            Err(BaseviewError::NullHandle("hwnd"))
+        ));
+
+        let window = rwh_05::AppKitWindowHandle::empty();
+        assert!(matches!(
+            rwh_06::RawWindowHandle::try_from(OldRawWindowHandle(window.into())),
+            Err(BaseviewError::NullHandle("ns_view"))
+        ));
+
+        let display = rwh_05::WaylandDisplayHandle::empty();
+        assert!(matches!(
+            rwh_06::RawDisplayHandle::try_from(OldRawDisplayHandle(display.into())),
+            Err(BaseviewError::NullHandle("display"))
+        ));
+    }
+
+    #[test]
+    fn rwh_06_parents_round_trip() {
+        let mut win32 = rwh_06::Win32WindowHandle::new(NonZero::new(0x10).unwrap());
+        win32.hinstance = NonZero::new(0x20);
+        let parents = [
+            rwh_06::RawWindowHandle::Xlib(rwh_06::XlibWindowHandle::new(42)),
+            rwh_06::RawWindowHandle::Xcb(rwh_06::XcbWindowHandle::new(NonZero::new(42).unwrap())),
+            rwh_06::RawWindowHandle::AppKit(rwh_06::AppKitWindowHandle::new(
+                NonNull::new(fake_pointer(0x30)).unwrap(),
+            )),
+            rwh_06::RawWindowHandle::Win32(win32),
+        ];
+
+        for parent in parents {
+            let old = ParentWindowHandle::try_from(parent).unwrap().raw_window_handle();
+            assert_eq!(
+                rwh_06::RawWindowHandle::try_from(OldRawWindowHandle(old)).unwrap(),
+                parent
+            );
         }
+
+        let wayland = rwh_06::RawWindowHandle::Wayland(rwh_06::WaylandWindowHandle::new(
+            NonNull::new(fake_pointer(0x40)).unwrap(),
+        ));
+        assert!(matches!(
+            ParentWindowHandle::try_from(wayland),
+            Err(BaseviewError::UnsupportedHandle(_))
+        ));
+    }
+
+    #[test]
+    fn win32_keeps_hinstance() {
+        let mut window = rwh_05::Win32WindowHandle::empty();
+        window.hwnd = fake_pointer(0x10);
+        window.hinstance = fake_pointer(0x20);
+
+        let mut expected = rwh_06::Win32WindowHandle::new(NonZero::new(0x10).unwrap());
+        expected.hinstance = NonZero::new(0x20);
+        assert_eq!(
+            rwh_06::RawWindowHandle::try_from(OldRawWindowHandle(window.into())).unwrap(),
+            rwh_06::RawWindowHandle::Win32(expected)
+        );
     }
-}
\ No newline at end of file
+}
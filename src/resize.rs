@@ -0,0 +1,115 @@
+use bevy::ecs::system::Resource;
+use bevy::math::Vec2;
+use bevy::window::WindowResizeConstraints;
+
+/// How the editor negotiates its size with the plugin host.
+///
+/// Sizes requested by Bevy systems through `Window::resolution` and sizes imposed by the
+/// host through `WindowEvent::Resized` are both fitted to `Window::resize_constraints`
+/// and [`aspect_ratio`](Self::aspect_ratio) before being applied.
+#[derive(Resource, Default)]
+pub struct ResizeNegotiation {
+    request_resize: Option<Box<dyn Fn(baseview::Size) -> bool + Send + Sync>>,
+    /// Width divided by height to snap every size to.
+    pub aspect_ratio: Option<f32>,
+}
+
+impl ResizeNegotiation {
+    /// Asks `request_resize` before resizing, e.g. forwarding to CLAP's `gui.request_resize`.
+    ///
+    /// The callback receives the logical size; returning `false` keeps the current size.
+    pub fn new(request_resize: impl Fn(baseview::Size) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            request_resize: Some(Box::new(request_resize)),
+            aspect_ratio: None,
+        }
+    }
+
+    pub fn with_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    /// Whether the host allows resizing to `size`. Without a callback every size is allowed.
+    pub fn request(&self, size: Vec2) -> bool {
+        self.request_resize.as_ref().is_none_or(|request_resize| {
+            request_resize(baseview::Size::new(size.x as f64, size.y as f64))
+        })
+    }
+}
+
+/// Fits a logical `size` into `constraints`, snapping it to `aspect_ratio` if given.
+pub fn constrain(
+    size: Vec2,
+    constraints: &WindowResizeConstraints,
+    aspect_ratio: Option<f32>,
+) -> Vec2 {
+    let constraints = constraints.check_constraints();
+    let min = Vec2::new(constraints.min_width, constraints.min_height);
+    let max = Vec2::new(constraints.max_width, constraints.max_height);
+
+    let size = size.clamp(min, max);
+
+    match aspect_ratio {
+        Some(aspect_ratio) if aspect_ratio > 0.0 => {
+            let height = (size.x / aspect_ratio).clamp(min.y, max.y);
+            let width = (height * aspect_ratio).clamp(min.x, max.x);
+            Vec2::new(width, height)
+        }
+        _ => size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_are_clamped_to_constraints() {
+        let constraints = WindowResizeConstraints {
+            min_width: 200.0,
+            min_height: 100.0,
+            max_width: 800.0,
+            max_height: 600.0,
+        };
+
+        assert_eq!(
+            constrain(Vec2::new(50.0, 50.0), &constraints, None),
+            Vec2::new(200.0, 100.0)
+        );
+        assert_eq!(
+            constrain(Vec2::new(900.0, 700.0), &constraints, None),
+            Vec2::new(800.0, 600.0)
+        );
+        assert_eq!(
+            constrain(Vec2::new(400.0, 300.0), &constraints, None),
+            Vec2::new(400.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn sizes_are_snapped_to_aspect_ratio() {
+        let constraints = WindowResizeConstraints {
+            max_height: 300.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            constrain(Vec2::new(400.0, 100.0), &constraints, Some(2.0)),
+            Vec2::new(400.0, 200.0)
+        );
+        assert_eq!(
+            constrain(Vec2::new(1000.0, 100.0), &constraints, Some(2.0)),
+            Vec2::new(600.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn host_can_refuse_resizing() {
+        let negotiation = ResizeNegotiation::new(|size| size.width <= 640.0);
+
+        assert!(negotiation.request(Vec2::new(640.0, 480.0)));
+        assert!(!negotiation.request(Vec2::new(1280.0, 960.0)));
+        assert!(ResizeNegotiation::default().request(Vec2::new(1280.0, 960.0)));
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy::ecs::system::Resource;
+
+/// When `BevyWindow` updates the app, modelled on bevy_winit's `WinitSettings`.
+///
+/// baseview calls `on_frame` at its own rate; the mode decides which of those frames run
+/// `App::update`. Without this resource the app updates on every frame, focused or not.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseviewSettings {
+    /// Used while the editor window has keyboard focus.
+    pub focused_mode: UpdateMode,
+    /// Used while the host or another window has focus.
+    pub unfocused_mode: UpdateMode,
+}
+
+impl BaseviewSettings {
+    /// Updates on every frame. Suits meters, oscilloscopes and playheads.
+    pub fn continuous() -> Self {
+        Self {
+            focused_mode: UpdateMode::Continuous,
+            unfocused_mode: UpdateMode::Continuous,
+        }
+    }
+
+    /// Updates on every frame while focused and not at all otherwise.
+    pub fn focused_only() -> Self {
+        Self {
+            focused_mode: UpdateMode::Continuous,
+            unfocused_mode: UpdateMode::Manual,
+        }
+    }
+
+    /// Updates on input, on `RequestRedraw`, or after `wait` at the latest.
+    pub fn reactive(wait: Duration) -> Self {
+        Self {
+            focused_mode: UpdateMode::Reactive { wait },
+            unfocused_mode: UpdateMode::Reactive { wait },
+        }
+    }
+
+    pub fn update_mode(&self, focused: bool) -> UpdateMode {
+        if focused {
+            self.focused_mode
+        } else {
+            self.unfocused_mode
+        }
+    }
+}
+
+impl Default for BaseviewSettings {
+    fn default() -> Self {
+        Self::continuous()
+    }
+}
+
+/// Lets code outside the app, such as the plugin's host or audio thread, ask for an update
+/// in the reactive modes, e.g. after a parameter changed.
+///
+/// Insert a clone while building the app and keep another one on the plugin side.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct RedrawSignal(Arc<AtomicBool>);
+
+impl RedrawSignal {
+    /// Updates the app on the next baseview frame. Can be called from any thread.
+    pub fn request(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub(crate) fn take(&self) -> bool {
+        self.0.swap(false, Ordering::Acquire)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Update on every baseview frame.
+    Continuous,
+    /// Update when baseview delivered an event, a system sent `RequestRedraw`, the host
+    /// used [`RedrawSignal`], or `wait` has passed since the last update.
+    Reactive { wait: Duration },
+    /// Update only when a system sent `RequestRedraw` or the host used [`RedrawSignal`].
+    Manual,
+}
+
+impl UpdateMode {
+    /// Whether the current frame should update the app.
+    pub(crate) fn should_update(
+        &self,
+        since_last_update: Duration,
+        had_events: bool,
+        redraw_requested: bool,
+    ) -> bool {
+        match self {
+            UpdateMode::Continuous => true,
+            UpdateMode::Reactive { wait } => {
+                had_events || redraw_requested || since_last_update >= *wait
+            }
+            UpdateMode::Manual => redraw_requested,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reactive_updates_on_events_redraws_and_timeout() {
+        let mode = UpdateMode::Reactive {
+            wait: Duration::from_millis(100),
+        };
+
+        assert!(!mode.should_update(Duration::from_millis(10), false, false));
+        assert!(mode.should_update(Duration::from_millis(10), true, false));
+        assert!(mode.should_update(Duration::from_millis(10), false, true));
+        assert!(mode.should_update(Duration::from_millis(100), false, false));
+    }
+
+    #[test]
+    fn manual_updates_only_on_redraw_requests() {
+        assert!(!UpdateMode::Manual.should_update(Duration::from_secs(60), true, false));
+        assert!(UpdateMode::Manual.should_update(Duration::ZERO, false, true));
+    }
+
+    #[test]
+    fn focus_selects_the_mode() {
+        let settings = BaseviewSettings::focused_only();
+
+        assert_eq!(settings.update_mode(true), UpdateMode::Continuous);
+        assert_eq!(settings.update_mode(false), UpdateMode::Manual);
+        assert_eq!(
+            BaseviewSettings::default().update_mode(false),
+            UpdateMode::Continuous
+        );
+    }
+}
@@ -1,112 +1,428 @@
 use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
 
 use bevy::input::ButtonState;
+use bevy::ecs::event::{EventCursor, Events};
 use bevy::ecs::system::SystemState;
-use bevy::prelude::{Entity, EventWriter, FromWorld, Query, With};
+use bevy::prelude::{Entity, EventWriter, Has, Query, Res, ResMut, With};
 
-use bevy::app::App;
+use bevy::app::{App, AppExit};
 use bevy::input::{
-    keyboard::KeyboardInput,
-    mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel},
+    keyboard::{Key, KeyCode, KeyboardFocusLost, KeyboardInput, NativeKey},
+    ButtonInput,
+    mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
 };
 use bevy::math::DVec2;
+use bevy::time::TimeUpdateStrategy;
 use bevy::window::{
-    CursorEntered, CursorLeft, CursorMoved, PrimaryWindow, RequestRedraw, Window, WindowBackendScaleFactorChanged, WindowFocused, WindowResized, WindowScaleFactorChanged
+    ClosingWindow, CursorEntered, CursorGrabMode, CursorLeft, CursorMoved, FileDragAndDrop, Ime, PrimaryWindow, RequestRedraw, Window, WindowBackendScaleFactorChanged, WindowClosed, WindowClosing, WindowFocused, WindowResized, WindowScaleFactorChanged
 };
 
+use keyboard_types::Modifiers;
+
+use crate::batching::{self, EventBatching};
+use crate::changed_window;
 use crate::conversions;
+use crate::crash::CrashHandler;
+use crate::cursor_icon::BaseviewCursorIcon;
+use crate::cursor_warp::PendingWarp;
+use crate::drag_drop::{self, DropTarget};
+use crate::error;
+use crate::event_status;
+use crate::frame_pacing::{FramePacer, FramePacing};
+use crate::ime::ImeState;
 use crate::keyboard;
+use crate::modifiers::{self, BaseviewModifiers, ModifierKeys};
+use crate::native_window::NativeWindow;
+use crate::resize::{self, ResizeNegotiation};
+use crate::update_mode::{BaseviewSettings, RedrawSignal, UpdateMode};
+
+type ProcessEventParams = (
+    (
+        EventWriter<'static, CursorMoved>,
+        EventWriter<'static, CursorEntered>,
+        EventWriter<'static, CursorLeft>,
+        EventWriter<'static, MouseButtonInput>,
+        EventWriter<'static, MouseWheel>,
+        EventWriter<'static, MouseMotion>,
+        EventWriter<'static, FileDragAndDrop>,
+        ResMut<'static, DropTarget>,
+    ),
+    (
+        EventWriter<'static, KeyboardInput>,
+        EventWriter<'static, KeyboardFocusLost>,
+        ResMut<'static, BaseviewModifiers>,
+        Option<Res<'static, ButtonInput<KeyCode>>>,
+        EventWriter<'static, Ime>,
+    ),
+    (
+        EventWriter<'static, WindowFocused>,
+        EventWriter<'static, WindowResized>,
+        EventWriter<'static, WindowScaleFactorChanged>,
+        EventWriter<'static, WindowBackendScaleFactorChanged>,
+        EventWriter<'static, WindowClosing>,
+        Option<Res<'static, ResizeNegotiation>>,
+    ),
+
+    Query<'static, 'static, (Entity, &'static mut Window), With<PrimaryWindow>>,
+);
+
+type FrameParams = (
+    Option<Res<'static, Events<RequestRedraw>>>,
+    Option<Res<'static, RedrawSignal>>,
+    Option<Res<'static, BaseviewSettings>>,
+    Option<Res<'static, FramePacing>>,
+    EventWriter<'static, Ime>,
 
-#[derive(Debug)]
+    Query<'static, 'static, (Entity, &'static mut Window), With<PrimaryWindow>>,
+);
+
+type SyncWindowParams = (
+    Query<'static, 'static, (&'static mut Window, Option<&'static BaseviewCursorIcon>), With<PrimaryWindow>>,
+    Option<Res<'static, ResizeNegotiation>>,
+);
+
+/// The `baseview::WindowHandler` driving a Bevy [`App`] inside a baseview window.
+///
+/// Created by the `open_*` functions, or wrapped by [`HeadlessWindow`](crate::HeadlessWindow)
+/// to drive an app without a native window.
 pub struct BevyWindow {
     app: App,
     last_scale_factor: f64,
     pending_events: VecDeque<baseview::Event>,
+    modifier_keys: ModifierKeys,
+    ime: ImeState,
+    window_cache: Option<Window>,
+    cursor: baseview::MouseCursor,
+    last_cursor_position: Option<DVec2>,
+    pending_warp: PendingWarp,
+    close_requested: bool,
+    closed: bool,
+    crash_handler: Option<CrashHandler>,
+    crashed: bool,
+    last_update: Instant,
+    events_since_update: bool,
+    redraw_requests: EventCursor<RequestRedraw>,
+    redraw_requested: bool,
+    frame_pacer: FramePacer,
+    // Built once for the app's world rather than per event or frame.
+    process_event_state: SystemState<ProcessEventParams>,
+    frame_state: SystemState<FrameParams>,
+    sync_window_state: SystemState<SyncWindowParams>,
+}
+
+impl std::fmt::Debug for BevyWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BevyWindow")
+            .field("pending_events", &self.pending_events)
+            .field("closed", &self.closed)
+            .field("crashed", &self.crashed)
+            .finish_non_exhaustive()
+    }
 }
 
 struct EventStatus {
-    return_status: baseview::EventStatus,
     shutdown: bool,
 }
 
 impl BevyWindow {
-    pub fn new(app: App) -> Self {
+    pub fn new(mut app: App) -> Self {
+        app.world_mut().init_resource::<BaseviewModifiers>();
+        app.world_mut().init_resource::<DropTarget>();
+        // Kept outside the world so it can still be reached once the app is dropped.
+        let crash_handler = app.world_mut().remove_resource::<CrashHandler>();
+
+        let process_event_state = SystemState::new(app.world_mut());
+        let frame_state = SystemState::new(app.world_mut());
+        let sync_window_state = SystemState::new(app.world_mut());
+
         Self {
             app,
             last_scale_factor: 1.0,
             pending_events: VecDeque::new(),
+            modifier_keys: ModifierKeys::default(),
+            ime: ImeState::default(),
+            window_cache: None,
+            cursor: baseview::MouseCursor::Default,
+            last_cursor_position: None,
+            pending_warp: PendingWarp::default(),
+            close_requested: false,
+            closed: false,
+            crash_handler,
+            crashed: false,
+            last_update: Instant::now(),
+            events_since_update: false,
+            redraw_requests: EventCursor::default(),
+            redraw_requested: false,
+            frame_pacer: FramePacer::default(),
+            process_event_state,
+            frame_state,
+            sync_window_state,
         }
     }
 
-    fn process_pending_events(&mut self) -> EventStatus {
-        let mut status = EventStatus {
-            return_status: baseview::EventStatus::Captured,
-            shutdown: false,
+    /// Feeds `event` to the app as `baseview::WindowHandler::on_event` would, returning the
+    /// status for baseview.
+    pub fn handle_event(&mut self, event: baseview::Event) -> baseview::EventStatus {
+        if self.closed || self.crashed {
+            return baseview::EventStatus::Ignored;
+        }
+
+        let batching = self
+            .app
+            .world()
+            .get_resource::<EventBatching>()
+            .copied()
+            .unwrap_or_default();
+
+        self.isolate(|window| {
+            let return_status = event_status::resolve(window.app.world_mut(), &event);
+            let flush = batching == EventBatching::Immediate || batching::is_will_close(&event);
+
+            window.pending_events.push_back(event);
+            if flush && window.process_pending_events().shutdown {
+                window.shutdown();
+            }
+
+            return_status
+        })
+        .unwrap_or(baseview::EventStatus::Ignored)
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// The handler left behind when the app failed to build; it ignores every event.
+    pub(crate) fn closed() -> Self {
+        let mut window = Self::new(App::empty());
+        window.closed = true;
+        window
+    }
+
+    /// Applies changes Bevy systems made to the primary window to the baseview window.
+    fn sync_window(&mut self, baseview_window: &mut impl NativeWindow) {
+        let (
+            mut windows,
+            negotiation,
+        ) = self.sync_window_state.get_mut(self.app.world_mut());
+
+        let Ok((mut window, cursor_icon)) = windows.get_single_mut() else {
+            return;
         };
 
-        while !self.pending_events.is_empty() {
-            let pending_event = self.pending_events.pop_front().unwrap();
-            let pending_status = self.process_event(pending_event);
-            if pending_status.shutdown {
-                status.shutdown = true;
+        let cursor = if window.cursor_options.visible
+            && window.cursor_options.grab_mode != CursorGrabMode::Locked
+        {
+            cursor_icon.map_or(baseview::MouseCursor::Default, conversions::bevy_cursor_icon_to_baseview)
+        } else {
+            baseview::MouseCursor::Hidden
+        };
+        if cursor != self.cursor {
+            baseview_window.set_mouse_cursor(cursor);
+            self.cursor = cursor;
+        }
+
+        // baseview cannot lock the pointer either, so a locked cursor that moved is warped
+        // back to the centre, leaving room for unbounded `MouseMotion`.
+        if window.cursor_options.grab_mode == CursorGrabMode::Locked {
+            let centre = (window.resolution.size() / 2.0).as_dvec2();
+            let now = Instant::now();
+            if !self.pending_warp.is_pending(now)
+                && self
+                    .last_cursor_position
+                    .is_some_and(|position| PendingWarp::should_warp(position, centre))
+                && baseview_window.warp_cursor(centre, window.resolution.scale_factor() as f64)
+            {
+                self.pending_warp.start(centre, now);
             }
+        } else {
+            self.pending_warp.clear();
         }
 
-        status
+        match &mut self.window_cache {
+            Some(cache) => {
+                if changed_window::apply(&mut window, cache, negotiation.as_deref(), baseview_window) {
+                    *cache = window.clone();
+                }
+            }
+            None => self.window_cache = Some(window.clone()),
+        }
     }
 
-    fn process_event(&mut self, event: baseview::Event) -> EventStatus {
-        let mut status = EventStatus {
-            return_status: baseview::EventStatus::Captured,
-            shutdown: false,
-        };
+    /// Whether the app asked for the editor to close: it sent `AppExit`, or the primary
+    /// window was despawned or marked `ClosingWindow` (the response to `WindowCloseRequested`).
+    ///
+    /// baseview then delivers `WindowEvent::WillClose`, which runs [`Self::shutdown`].
+    fn should_close(&mut self) -> bool {
+        if self.app.should_exit().is_some() {
+            return true;
+        }
 
-        let mut process_event_system_state: SystemState<(
-            //EventReader<CloseAppRequest>,
-            //EventWriter<CloseAppResponse>,
+        let world = self.app.world_mut();
+        world
+            .query_filtered::<Has<ClosingWindow>, With<PrimaryWindow>>()
+            .iter(world)
+            .next()
+            .unwrap_or(true)
+    }
 
-            EventWriter<CursorMoved>,
-            EventWriter<CursorEntered>,
-            EventWriter<CursorLeft>,
+    /// Tears the App down after baseview announced `WindowEvent::WillClose`.
+    ///
+    /// Systems first get an update to observe `WindowClosing` while the primary window still
+    /// exists, e.g. to save the editor size, then one last update to observe `WindowClosed`
+    /// and `AppExit`. The App and its render world are then dropped while the native window
+    /// still exists, so reopening the editor does not leak surfaces or GPU memory.
+    fn shutdown(&mut self) {
+        if self.closed {
+            return;
+        }
+        self.closed = true;
 
-            EventWriter<MouseButtonInput>,
-            EventWriter<MouseWheel>,
+        self.app.update();
 
-            EventWriter<KeyboardInput>,
+        let world = self.app.world_mut();
+        let windows: Vec<Entity> = world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .iter(world)
+            .collect();
+        for window in windows {
+            world.despawn(window);
+            world.send_event(WindowClosed { window });
+        }
+        world.send_event(AppExit::Success);
 
-            EventWriter<WindowFocused>,
-            EventWriter<WindowResized>,
-            EventWriter<WindowScaleFactorChanged>,
-            EventWriter<WindowBackendScaleFactorChanged>,
+        self.app.update();
 
-            Query<(Entity, &mut Window), With<PrimaryWindow>>,
-        )> = SystemState::from_world(self.app.world_mut());
+        drop(std::mem::replace(&mut self.app, App::empty()));
+        log::info!("BaseviewWindow: app dropped");
+    }
 
-        let (
-            //mut close_app_requests,
-            //mut close_app_responses,
+    /// Runs `f`, catching a panic so it does not unwind into the host's callback.
+    ///
+    /// After a panic the app is dropped, [`CrashHandler`] is told why, and the window ignores
+    /// everything until the host closes it. Returns `None` if `f` panicked.
+    fn isolate<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> Option<T> {
+        match panic::catch_unwind(AssertUnwindSafe(|| f(self))) {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                self.crash(error::panic_message(&*payload));
+                None
+            }
+        }
+    }
+
+    fn crash(&mut self, message: String) {
+        log::error!("BaseviewWindow: app panicked, no longer updating: {}", message);
+        self.crashed = true;
+        self.pending_events.clear();
+
+        if let Some(crash_handler) = &self.crash_handler {
+            if panic::catch_unwind(AssertUnwindSafe(|| crash_handler.report(&message))).is_err() {
+                log::error!("BaseviewWindow: crash handler panicked");
+            }
+        }
+
+        // The app may be in an inconsistent state, so dropping it can panic as well.
+        let app = std::mem::replace(&mut self.app, App::empty());
+        if panic::catch_unwind(AssertUnwindSafe(move || drop(app))).is_err() {
+            log::error!("BaseviewWindow: dropping the crashed app panicked");
+        }
+    }
+
+    fn process_pending_events(&mut self) -> EventStatus {
+        let mut status = EventStatus {
+            shutdown: false,
+        };
+
+        let coalesce = self
+            .app
+            .world()
+            .get_resource::<EventBatching>()
+            .copied()
+            .unwrap_or_default()
+            == EventBatching::Coalesced;
 
-            mut cursor_moved_events,
-            mut cursor_entered_events,
-            mut cursor_left_events,
+        while let Some(pending_event) = self.pending_events.pop_front() {
+            self.events_since_update = true;
+
+            // Only the last of consecutive moves is converted; its delta from the last
+            // converted position is the sum of the skipped ones. Without a last position,
+            // e.g. after the cursor left, the first skipped move provides it.
+            if coalesce
+                && batching::is_cursor_moved(&pending_event)
+                && self.pending_events.front().is_some_and(batching::is_cursor_moved)
+            {
+                if let baseview::Event::Mouse(baseview::MouseEvent::CursorMoved {
+                    position, ..
+                }) = pending_event
+                {
+                    let position = DVec2::new(position.x, position.y);
+                    if self.pending_warp.take_echo(position) {
+                        self.last_cursor_position = Some(position);
+                    } else {
+                        self.last_cursor_position.get_or_insert(position);
+                    }
+                }
+                continue;
+            }
 
-            mut mouse_button_input_events,
-            mut mouse_wheel_events,
+            if self.process_event(pending_event).shutdown {
+                status.shutdown = true;
+            }
+        }
 
-            mut keyboard_input_events,
+        status
+    }
 
-            mut window_focused_events,
-            mut window_resized_events,
-            mut window_scale_factor_changed_evnets,
-            mut window_backend_scale_factor_changed_events,
+    fn process_event(&mut self, event: baseview::Event) -> EventStatus {
+        let mut status = EventStatus {
+            shutdown: false,
+        };
+
+        let (
+            (
+                mut cursor_moved_events,
+                mut cursor_entered_events,
+                mut cursor_left_events,
+                mut mouse_button_input_events,
+                mut mouse_wheel_events,
+                mut mouse_motion_events,
+                mut file_drag_and_drop_events,
+                mut drop_target,
+            ),
+            (
+                mut keyboard_input_events,
+                mut keyboard_focus_lost_events,
+                mut modifiers_state,
+                key_input,
+                mut ime_events,
+            ),
+            (
+                mut window_focused_events,
+                mut window_resized_events,
+                mut window_scale_factor_changed_evnets,
+                mut window_backend_scale_factor_changed_events,
+                mut window_closing_events,
+                resize_negotiation,
+            ),
 
             mut window_entity
-        ) = process_event_system_state.get_mut(self.app.world_mut());
+        ) = self.process_event_state.get_mut(self.app.world_mut());
 
-        // for _ in close_app_requests.read() {
-        //     status.shutdown = true;
-        //     //close_app_responses.send(CloseAppResponse);
-        // }
+        if let Some(reported) = modifiers::event_modifiers(&event) {
+            if let Ok((entity, _window)) = window_entity.get_single() {
+                for input in self.modifier_keys.sync(entity, reported) {
+                    keyboard_input_events.send(input);
+                }
+            }
+            modifiers_state.0 = reported;
+        }
 
         match event {
             baseview::Event::Mouse(e) => {
@@ -115,12 +431,40 @@ impl BevyWindow {
                         match window_entity.get_single_mut(){
                             Ok((entity, mut window)) => {
                                 let position = DVec2::new(position.x, position.y);
-                                window.set_physical_cursor_position(Some(position));
-                                cursor_moved_events.send(CursorMoved {
-                                    window: entity,
-                                    position: position.as_vec2(),
-                                    delta: None,
-                                });
+                                if self.pending_warp.take_echo(position) {
+                                    self.last_cursor_position = Some(position);
+                                    return status;
+                                }
+
+                                let delta = self
+                                    .last_cursor_position
+                                    .map(|last_position| (position - last_position).as_vec2());
+                                self.last_cursor_position = Some(position);
+
+                                if let Some(delta) = delta {
+                                    mouse_motion_events.send(MouseMotion { delta });
+                                }
+
+                                // baseview cannot confine the pointer. A locked cursor is hidden,
+                                // keeps its position so only `MouseMotion` moves, and is warped
+                                // back to the centre by `sync_window` on X11.
+                                let position = match window.cursor_options.grab_mode {
+                                    CursorGrabMode::Locked => None,
+                                    CursorGrabMode::Confined => {
+                                        let size = window.resolution.physical_size().as_dvec2();
+                                        Some(position.clamp(DVec2::ZERO, size))
+                                    }
+                                    CursorGrabMode::None => Some(position),
+                                };
+
+                                if let Some(position) = position {
+                                    window.set_physical_cursor_position(Some(position));
+                                    cursor_moved_events.send(CursorMoved {
+                                        window: entity,
+                                        position: position.as_vec2(),
+                                        delta,
+                                    });
+                                }
                             },
                             Err(err) => {
                                 log::info!("Skipped event for closed window: {:?}", err);
@@ -172,6 +516,8 @@ impl BevyWindow {
                     baseview::MouseEvent::CursorLeft => {
                         match window_entity.get_single_mut(){
                             Ok((entity, _window)) => {
+                                self.last_cursor_position = None;
+                                self.pending_warp.clear();
                                 cursor_left_events.send(CursorLeft { window: entity });
                             },
                             Err(err) => {
@@ -214,21 +560,85 @@ impl BevyWindow {
                             }
                         }
                     },
-                    _ => {}
+                    baseview::MouseEvent::DragEntered { position, ref data, .. }
+                    | baseview::MouseEvent::DragMoved { position, ref data, .. }
+                    | baseview::MouseEvent::DragDropped { position, ref data, .. } => {
+                        match window_entity.get_single_mut(){
+                            Ok((entity, mut window)) => {
+                                let position = DVec2::new(position.x, position.y);
+                                self.last_cursor_position = Some(position);
+                                window.set_physical_cursor_position(Some(position));
+                                cursor_moved_events.send(CursorMoved {
+                                    window: entity,
+                                    position: position.as_vec2(),
+                                    delta: None,
+                                });
+
+                                match e {
+                                    baseview::MouseEvent::DragEntered { .. } => {
+                                        file_drag_and_drop_events
+                                            .send_batch(drag_drop::hovered(entity, data));
+                                    }
+                                    baseview::MouseEvent::DragDropped { .. } => {
+                                        file_drag_and_drop_events
+                                            .send_batch(drag_drop::dropped(entity, data));
+                                        *drop_target = DropTarget::default();
+                                    }
+                                    _ => {}
+                                }
+                            },
+                            Err(err) => {
+                                log::info!("Skipped event for closed window: {:?}", err);
+                                return status;
+                            },
+                        }
+                    }
+                    baseview::MouseEvent::DragLeft => {
+                        match window_entity.get_single_mut(){
+                            Ok((entity, _window)) => {
+                                self.last_cursor_position = None;
+                                self.pending_warp.clear();
+                                file_drag_and_drop_events
+                                    .send(FileDragAndDrop::HoveredFileCanceled { window: entity });
+                                *drop_target = DropTarget::default();
+                            },
+                            Err(err) => {
+                                log::info!("Skipped event for closed window: {:?}", err);
+                                return status;
+                            },
+                        }
+                    }
                 };
             }
             baseview::Event::Keyboard(e) => {
                 match window_entity.get_single_mut(){
-                    Ok((entity, _window)) => {
-                        let key_code = keyboard::key_to_keycode(e.key.clone());
+                    Ok((entity, window)) => {
+                        if let Some(event) = self.ime.set_enabled(entity, window.ime_enabled) {
+                            ime_events.send(event);
+                        }
+                        let ime = self.ime.process(entity, &e);
+                        // Keys that compose or commit text reach text fields through `Ime`
+                        // only, so they are not typed a second time from `KeyboardInput`.
+                        let composed = !ime.is_empty();
+                        ime_events.send_batch(ime);
+
+                        let key_code = keyboard::code_to_keycode(e.code);
                         let state = match e.state {
                             keyboard_types::KeyState::Down => ButtonState::Pressed,
                             keyboard_types::KeyState::Up => ButtonState::Released,
                         };
+                        self.modifier_keys.observe(key_code, state);
+                        if modifiers::is_modifier_key(key_code) {
+                            modifiers_state.0 = self.modifier_keys.merge(e.modifiers);
+                        }
                         let event = KeyboardInput {
                             window: entity,
                             key_code,
-                            logical_key: keyboard::key_to_bevy_key(e.key),
+                            logical_key: if composed {
+                                Key::Unidentified(NativeKey::Unidentified)
+                            } else {
+                                keyboard::key_to_bevy_key(e.key)
+                            },
                             repeat: e.repeat,
                             state,
                         };
@@ -269,10 +679,27 @@ impl BevyWindow {
                                     window_info.physical_size().width,
                                     window_info.physical_size().height,
                                 );
+                                // Host-driven, so it must not be pushed back to baseview.
+                                if let Some(cache) = &mut self.window_cache {
+                                    cache.resolution = window.resolution.clone();
+                                }
+
+                                // A size outside our constraints is corrected here; the next
+                                // frame's window sync then asks the host for the fitted size.
+                                let host_size = window.resolution.size();
+                                let size = resize::constrain(
+                                    host_size,
+                                    &window.resize_constraints,
+                                    resize_negotiation.as_ref().and_then(|negotiation| negotiation.aspect_ratio),
+                                );
+                                if size != host_size {
+                                    window.resolution.set(size.x, size.y);
+                                }
+
                                 window_resized_events.send(WindowResized {
                                     window: entity,
-                                    width: window_info.logical_size().width as f32,
-                                    height: window_info.logical_size().height as f32,
+                                    width: window.resolution.width(),
+                                    height: window.resolution.height(),
                                 });
                             }
                             baseview::WindowEvent::Focused => {
@@ -283,6 +710,28 @@ impl BevyWindow {
                                 });
                             }
                             baseview::WindowEvent::Unfocused => {
+                                // The host gets every key event while we are unfocused, so
+                                // release everything rather than risk keys getting stuck.
+                                let mut released: Vec<KeyCode> = key_input
+                                    .as_ref()
+                                    .map(|key_input| key_input.get_pressed().copied().collect())
+                                    .unwrap_or_default();
+                                released.extend(self.modifier_keys.held());
+                                released.sort_unstable();
+                                released.dedup();
+                                for key_code in released {
+                                    keyboard_input_events.send(KeyboardInput {
+                                        window: entity,
+                                        key_code,
+                                        logical_key: Key::Unidentified(NativeKey::Unidentified),
+                                        repeat: false,
+                                        state: ButtonState::Released,
+                                    });
+                                }
+                                keyboard_focus_lost_events.send(KeyboardFocusLost);
+                                self.modifier_keys.clear();
+                                modifiers_state.0 = Modifiers::empty();
+
                                 window.focused = false;
                                 window_focused_events.send(WindowFocused {
                                     window: entity,
@@ -290,6 +739,7 @@ impl BevyWindow {
                                 });
                             }
                             baseview::WindowEvent::WillClose => {
+                                window_closing_events.send(WindowClosing { window: entity });
                                 status.shutdown = true;
                             }
                         }
@@ -303,57 +753,506 @@ impl BevyWindow {
         }
         status
     }
-}
 
-impl Drop for BevyWindow {
-    fn drop(&mut self) {
-        log::info!("BaseviewWindow: drop");
+    /// Runs one `App::update`, publishing its [`FrameDiagnostics`](crate::FrameDiagnostics) and frame time first.
+    fn update(&mut self, pacing: &FramePacing) {
+        let start = Instant::now();
+        let diagnostics = self.frame_pacer.begin(start, pacing);
+
+        let world = self.app.world_mut();
+        world.insert_resource(diagnostics);
+        if let Some(mut strategy) = world.get_resource_mut::<TimeUpdateStrategy>() {
+            if !matches!(*strategy, TimeUpdateStrategy::ManualDuration(_)) {
+                *strategy = TimeUpdateStrategy::ManualInstant(start);
+            }
+        }
+
+        self.app.update();
+
+        self.frame_pacer.end(start.elapsed());
+        self.last_update = Instant::now();
+        self.events_since_update = false;
+        self.redraw_requested = false;
     }
-}
 
-impl baseview::WindowHandler for BevyWindow {
-    fn on_frame(&mut self, _window: &mut baseview::Window) {
-        self.process_pending_events();
+    /// Runs [`Self::frame`] unless the window is closed or crashed, isolating panics.
+    pub(crate) fn run_frame(&mut self, baseview_window: &mut impl NativeWindow) {
+        if self.closed || self.crashed {
+            return;
+        }
+
+        self.isolate(|window| window.frame(baseview_window));
+    }
+
+    /// One baseview frame: deliver queued events, update the app and sync the window.
+    fn frame(&mut self, baseview_window: &mut impl NativeWindow) {
+        if self.process_pending_events().shutdown {
+            self.shutdown();
+            return;
+        }
+
+        self.update_if_needed();
 
-        let mut frame_system_state: SystemState<(
-            EventWriter<RequestRedraw>,
+        self.sync_window(baseview_window);
 
-            Query<(Entity, &mut Window), With<PrimaryWindow>>,
-        )> = SystemState::from_world(self.app.world_mut());
+        if !self.close_requested && self.should_close() {
+            log::info!("BaseviewWindow: closing on request from the app");
+            self.close_requested = true;
+            baseview_window.close();
+        }
+    }
 
+    /// Updates the app if its [`UpdateMode`] and [`FramePacing`] call for it on this frame.
+    ///
+    /// Idle editors are not updated at all in the reactive modes: an update needs an event
+    /// from baseview, a `RequestRedraw` from a system, a [`RedrawSignal`] from the host, or
+    /// the reactive timeout.
+    fn update_if_needed(&mut self) {
         let (
-            mut request_redraw_events,
+            request_redraw_events,
+            redraw_signal,
+            settings,
+            pacing,
+            mut ime_events,
 
             mut window_entity,
-        ) = frame_system_state.get_mut(self.app.world_mut());
+        ) = self.frame_state.get_mut(self.app.world_mut());
 
-        request_redraw_events.send(RequestRedraw);
+        // Kept until an update runs, as pacing may hold this frame back.
+        if let Some(events) = request_redraw_events {
+            self.redraw_requested |= self.redraw_requests.read(&events).count() > 0;
+        }
+        if let Some(redraw_signal) = redraw_signal {
+            self.redraw_requested |= redraw_signal.take();
+        }
 
-        match window_entity.get_single_mut() {
-            Ok((_entity, window)) => {
-                if window.focused {
-                    self.app.update();
+        let pacing = pacing.as_deref().copied().unwrap_or_default();
+        let should_update = match window_entity.get_single_mut() {
+            Ok((entity, window)) => {
+                if let Some(event) = self.ime.set_enabled(entity, window.ime_enabled) {
+                    ime_events.send(event);
                 }
-            }, 
-            _ => {}
+
+                let update_mode = settings
+                    .map(|settings| settings.update_mode(window.focused))
+                    .unwrap_or(UpdateMode::Continuous);
+                self.frame_pacer.is_due(Instant::now())
+                    && update_mode.should_update(
+                        self.last_update.elapsed(),
+                        self.events_since_update,
+                        self.redraw_requested,
+                    )
+            },
+            Err(_) => false,
+        };
+
+        if should_update {
+            self.update(&pacing);
         }
     }
+}
+
+impl Drop for BevyWindow {
+    fn drop(&mut self) {
+        log::info!("BaseviewWindow: drop");
+    }
+}
+
+impl baseview::WindowHandler for BevyWindow {
+    fn on_frame(&mut self, baseview_window: &mut baseview::Window) {
+        self.run_frame(baseview_window);
+    }
 
     fn on_event(
         &mut self,
         _window: &mut baseview::Window,
         event: baseview::Event,
     ) -> baseview::EventStatus {
-        //let gui_thread = GuiThread;
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use bevy::input::keyboard::NativeKeyCode;
+    use bevy::input::InputPlugin;
+    use bevy::prelude::{EventReader, Update};
+    use bevy::window::WindowPlugin;
+
+    use bevy::window::SystemCursorIcon;
+
+    use super::*;
+    use crate::native_window::RecordingWindow;
+
+    /// Sets its flag when the app holding it is dropped.
+    #[derive(bevy::ecs::system::Resource)]
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn will_close_runs_a_final_update_then_drops_the_app() {
+        let saw_exit = Arc::new(AtomicBool::new(false));
+        let closing_width = Arc::new(std::sync::Mutex::new(None));
+        let dropped = Arc::new(AtomicBool::new(false));
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.insert_resource(DropFlag(dropped.clone()));
+        let width = closing_width.clone();
+        app.add_systems(
+            Update,
+            move |mut closing: EventReader<WindowClosing>, windows: Query<&Window>| {
+                for event in closing.read() {
+                    *width.lock().unwrap() = windows.get(event.window).ok().map(Window::width);
+                }
+            },
+        );
+        let flag = saw_exit.clone();
+        app.add_systems(
+            Update,
+            move |mut closed: EventReader<WindowClosed>, mut exit: EventReader<AppExit>| {
+                if closed.read().count() > 0 && exit.read().count() > 0 {
+                    flag.store(true, Ordering::SeqCst);
+                }
+            },
+        );
+        app.finish();
+        app.cleanup();
+        app.update();
 
-        self.pending_events.push_back(event);
+        let mut window = BevyWindow::new(app);
+        let status = window.process_event(baseview::Event::Window(baseview::WindowEvent::WillClose));
+        assert!(status.shutdown);
+        assert!(!dropped.load(Ordering::SeqCst));
 
-        let status = self.process_pending_events();
+        window.shutdown();
 
-        // if status.shutdown {
-        //     drop_app(&gui_thread);
-        // }
+        assert_eq!(
+            *closing_width.lock().unwrap(),
+            Some(Window::default().width())
+        );
+        assert!(saw_exit.load(Ordering::SeqCst));
+        assert!(window.closed);
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn panics_are_reported_and_stop_the_app() {
+        let reported = Arc::new(std::sync::Mutex::new(None));
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        let report = reported.clone();
+        app.insert_resource(CrashHandler::new(move |message| {
+            *report.lock().unwrap() = Some(message.to_string());
+        }));
+        app.add_systems(Update, || panic!("system exploded"));
+        app.finish();
+        app.cleanup();
+
+        let mut window = BevyWindow::new(app);
+        assert_eq!(window.isolate(|window| window.app.update()), None);
+
+        assert!(window.crashed);
+        assert_eq!(reported.lock().unwrap().as_deref(), Some("system exploded"));
+        assert!(window.app.world().entities().is_empty());
+    }
+
+    #[test]
+    fn reactive_mode_stays_idle_without_input() {
+        #[derive(bevy::prelude::Resource, Default)]
+        struct Updates(usize);
+
+        let signal = RedrawSignal::default();
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.insert_resource(BaseviewSettings::reactive(std::time::Duration::from_secs(3600)));
+        app.insert_resource(signal.clone());
+        app.init_resource::<Updates>();
+        app.add_systems(Update, |mut updates: ResMut<Updates>| updates.0 += 1);
+        app.finish();
+        app.cleanup();
+
+        let mut window = BevyWindow::new(app);
+        let updates = |window: &BevyWindow| window.app.world().resource::<Updates>().0;
+
+        for _ in 0..1000 {
+            window.update_if_needed();
+        }
+        assert_eq!(updates(&window), 0);
+
+        window.pending_events.push_back(baseview::Event::Mouse(baseview::MouseEvent::CursorMoved {
+            position: baseview::Point::new(10.0, 10.0),
+            modifiers: Modifiers::empty(),
+        }));
+        window.process_pending_events();
+        window.update_if_needed();
+        assert_eq!(updates(&window), 1);
+
+        window.app.world_mut().send_event(RequestRedraw);
+        window.update_if_needed();
+        assert_eq!(updates(&window), 2);
+
+        signal.request();
+        window.update_if_needed();
+        assert_eq!(updates(&window), 3);
+
+        for _ in 0..1000 {
+            window.update_if_needed();
+        }
+        assert_eq!(updates(&window), 3);
+    }
+
+    #[test]
+    fn warp_echoes_are_not_reported_as_motion() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        let mut window = BevyWindow::new(app);
+        let cursor_moved = |x: f64| {
+            baseview::Event::Mouse(baseview::MouseEvent::CursorMoved {
+                position: baseview::Point::new(x, 50.0),
+                modifiers: Modifiers::empty(),
+            })
+        };
+
+        window.last_cursor_position = Some(DVec2::new(90.0, 50.0));
+        window.pending_warp.start(DVec2::new(50.0, 50.0), Instant::now());
+        window.process_event(cursor_moved(95.0));
+        window.process_event(cursor_moved(50.0));
+        window.process_event(cursor_moved(52.0));
+        assert!(!window.pending_warp.is_pending(Instant::now()));
+
+        let motion: Vec<f32> = EventCursor::<MouseMotion>::default()
+            .read(window.app.world().resource::<Events<MouseMotion>>())
+            .map(|event| event.delta.x)
+            .collect();
+        assert_eq!(motion, vec![5.0, 2.0]);
+    }
+
+    #[test]
+    fn unreported_warps_do_not_block_later_warps() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        let mut window = BevyWindow::new(app);
+        let cursor_moved = |x: f64| {
+            baseview::Event::Mouse(baseview::MouseEvent::CursorMoved {
+                position: baseview::Point::new(x, 50.0),
+                modifiers: Modifiers::empty(),
+            })
+        };
+
+        // The user moves before the warp is reported, and the OS never reports it.
+        let warped_at = Instant::now();
+        window.last_cursor_position = Some(DVec2::new(90.0, 50.0));
+        window.pending_warp.start(DVec2::new(50.0, 50.0), warped_at);
+        window.process_event(cursor_moved(95.0));
+        assert!(window.pending_warp.is_pending(warped_at));
+
+        assert!(!window
+            .pending_warp
+            .is_pending(warped_at + std::time::Duration::from_secs(1)));
+        window.process_event(cursor_moved(50.0));
+
+        let motion: Vec<f32> = EventCursor::<MouseMotion>::default()
+            .read(window.app.world().resource::<Events<MouseMotion>>())
+            .map(|event| event.delta.x)
+            .collect();
+        assert_eq!(motion, vec![5.0, -45.0]);
+    }
 
-        status.return_status
+    #[test]
+    fn composed_text_arrives_once() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.finish();
+        app.cleanup();
+        app.update();
+        let world = app.world_mut();
+        world
+            .query_filtered::<&mut Window, With<PrimaryWindow>>()
+            .single_mut(world)
+            .ime_enabled = true;
+
+        let mut window = BevyWindow::new(app);
+        let key_down = |key: keyboard_types::Key| {
+            baseview::Event::Keyboard(keyboard_types::KeyboardEvent {
+                state: keyboard_types::KeyState::Down,
+                key,
+                ..Default::default()
+            })
+        };
+        window.process_event(key_down(keyboard_types::Key::Dead));
+        window.process_event(key_down(keyboard_types::Key::Character("é".to_string())));
+        window.process_event(key_down(keyboard_types::Key::Character("a".to_string())));
+
+        let world = window.app.world();
+        let mut text = String::new();
+        for event in EventCursor::<Ime>::default().read(world.resource::<Events<Ime>>()) {
+            if let Ime::Commit { value, .. } = event {
+                text.push_str(value);
+            }
+        }
+        for event in EventCursor::<KeyboardInput>::default()
+            .read(world.resource::<Events<KeyboardInput>>())
+        {
+            if let Key::Character(value) = &event.logical_key {
+                text.push_str(value);
+            }
+        }
+        assert_eq!(text, "éa");
+    }
+
+    #[test]
+    fn unidentified_codes_are_not_guessed_from_the_key() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        let mut window = BevyWindow::new(app);
+        window.process_event(baseview::Event::Keyboard(keyboard_types::KeyboardEvent {
+            state: keyboard_types::KeyState::Down,
+            key: keyboard_types::Key::Character("a".to_string()),
+            code: keyboard_types::Code::Unidentified,
+            ..Default::default()
+        }));
+
+        let world = window.app.world();
+        let key_codes: Vec<_> = EventCursor::<KeyboardInput>::default()
+            .read(world.resource::<Events<KeyboardInput>>())
+            .map(|event| event.key_code)
+            .collect();
+        assert_eq!(key_codes, [KeyCode::Unidentified(NativeKeyCode::Unidentified)]);
+    }
+
+    #[test]
+    fn cursor_changes_reach_the_native_window() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        let mut window = BevyWindow::new(app);
+        let mut native = RecordingWindow::default();
+        let world = window.app.world_mut();
+        let entity = world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(world);
+        window
+            .app
+            .world_mut()
+            .entity_mut(entity)
+            .insert(BaseviewCursorIcon(SystemCursorIcon::Text));
+        window.sync_window(&mut native);
+        assert_eq!(native.cursor, Some(baseview::MouseCursor::Text));
+
+        window
+            .app
+            .world_mut()
+            .get_mut::<Window>(entity)
+            .unwrap()
+            .cursor_options
+            .grab_mode = CursorGrabMode::Locked;
+        window.sync_window(&mut native);
+        assert_eq!(native.cursor, Some(baseview::MouseCursor::Hidden));
+
+        native.cursor = None;
+        window.sync_window(&mut native);
+        assert_eq!(native.cursor, None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn consecutive_cursor_moves_are_coalesced() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        let mut window = BevyWindow::new(app);
+        let cursor_moved = |x: f64| {
+            baseview::Event::Mouse(baseview::MouseEvent::CursorMoved {
+                position: baseview::Point::new(x, 0.0),
+                modifiers: Modifiers::empty(),
+            })
+        };
+        let events = [
+            cursor_moved(0.0),
+            cursor_moved(5.0),
+            cursor_moved(10.0),
+            baseview::Event::Mouse(baseview::MouseEvent::ButtonPressed {
+                button: baseview::MouseButton::Left,
+                modifiers: Modifiers::empty(),
+            }),
+            cursor_moved(20.0),
+            cursor_moved(40.0),
+        ];
+        for event in events {
+            window.handle_event(event);
+        }
+        assert_eq!(window.pending_events.len(), 6);
+
+        window.process_pending_events();
+        assert!(window.pending_events.is_empty());
+
+        let world = window.app.world();
+        let positions: Vec<f32> = EventCursor::<CursorMoved>::default()
+            .read(world.resource::<Events<CursorMoved>>())
+            .map(|event| event.position.x)
+            .collect();
+        assert_eq!(positions, vec![10.0, 40.0]);
+
+        let motion: Vec<f32> = EventCursor::<MouseMotion>::default()
+            .read(world.resource::<Events<MouseMotion>>())
+            .map(|event| event.delta.x)
+            .collect();
+        assert_eq!(motion, vec![10.0, 30.0]);
+        assert_eq!(motion.iter().sum::<f32>(), 40.0);
+
+        let presses = EventCursor::<MouseButtonInput>::default()
+            .read(world.resource::<Events<MouseButtonInput>>())
+            .count();
+        assert_eq!(presses, 1);
+    }
+
+    #[test]
+    fn despawning_the_primary_window_requests_close() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, WindowPlugin::default()));
+        app.finish();
+        app.cleanup();
+        app.update();
+
+        let mut window = BevyWindow::new(app);
+        assert!(!window.should_close());
+
+        let world = window.app.world_mut();
+        let primary = world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(world);
+        world.entity_mut(primary).insert(ClosingWindow);
+        assert!(window.should_close());
+
+        window.app.world_mut().despawn(primary);
+        assert!(window.should_close());
+    }
+}